@@ -1,5 +1,4 @@
 use snoo::{Reddit, SearchSort};
-use tokio;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -15,7 +14,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         for p in result.results().iter() {
             println!("/r/{}\t\t{}", p.info().subreddit, p.info().title);
         }
-        print!("\n");
+        println!();
         search = result.next().await?;
     }
     Ok(())
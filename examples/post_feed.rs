@@ -1,5 +1,4 @@
 use snoo::Reddit;
-use tokio;
 use tokio::time::Duration;
 
 //work in progress
@@ -1,6 +1,5 @@
 use snoo::Reddit;
 use snoo::SearchSort;
-use tokio;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -1,5 +1,4 @@
 use snoo::Reddit;
-use tokio;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
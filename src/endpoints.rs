@@ -40,6 +40,70 @@ impl SearchSort {
     }
 }
 
+/// The order comments are loaded in.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CommentSort {
+    Confidence,
+    Top,
+    New,
+    Controversial,
+    Old,
+    QA,
+    Live,
+}
+
+impl CommentSort {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            CommentSort::Confidence => "confidence",
+            CommentSort::Top => "top",
+            CommentSort::New => "new",
+            CommentSort::Controversial => "controversial",
+            CommentSort::Old => "old",
+            CommentSort::QA => "qa",
+            CommentSort::Live => "live",
+        }
+    }
+}
+
+/// The moderation queues of a subreddit.
+pub enum ModQueue {
+    /// Posts and comments that are reported or caught by the spam filter.
+    ModQueue,
+    Reports,
+    Spam,
+    /// Posts that have not been approved or removed.
+    Unmoderated,
+    Edited,
+}
+
+impl ModQueue {
+    pub fn endpoint(&self) -> EndpointBuilder {
+        match self {
+            ModQueue::ModQueue => ABOUT_MODQUEUE,
+            ModQueue::Reports => ABOUT_REPORTS,
+            ModQueue::Spam => ABOUT_SPAM,
+            ModQueue::Unmoderated => ABOUT_UNMODERATED,
+            ModQueue::Edited => ABOUT_EDITED,
+        }
+    }
+}
+
+/// Limits a moderation queue to one type of thing.
+pub enum QueueOnly {
+    Links,
+    Comments,
+}
+
+impl QueueOnly {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            QueueOnly::Links => "links",
+            QueueOnly::Comments => "comments",
+        }
+    }
+}
+
 pub enum EndpointBase {
     Regular,
     OAuth,
@@ -72,7 +136,9 @@ impl EndpointBuilder {
         subreddit,
         id,
         page,
-        user
+        user,
+        comment,
+        multi
     }
 
     pub fn regular_ep(self) -> io::Result<Endpoint> {
@@ -118,6 +184,12 @@ impl Endpoint {
         Ok(EndpointBuilder::new(url.path()))
     }
 
+    /// An endpoint at any url, e.g. a local test server.
+    #[cfg(test)]
+    pub(crate) fn from_url(url: Url) -> Endpoint {
+        Endpoint(url)
+    }
+
     pub fn to_url(&self) -> Url {
         self.0.clone()
     }
@@ -142,7 +214,7 @@ impl Endpoint {
             }
 
             if let Some(before_thing) = before {
-                query.append_pair("before", &before_thing.as_ref());
+                query.append_pair("before", before_thing.as_ref());
             }
         }
         self
@@ -233,6 +305,7 @@ endpoints! {
     INFO =>                    "api/info/",
     KARMA =>                   "api/v1/me/karma/",
     LEAVECONTRIBUTOR =>        "api/leavecontributor/",
+    LEAVEMODERATOR =>          "api/leavemoderator/",
     LINK_FLAIR =>              "r/#subreddit/api/link_flair_v2",
     LIST_BANNED =>             "r/#subreddit/about/banned/",
     LIST_CONTRIBUTOR =>        "r/#subreddit/about/contributors/",
@@ -263,7 +336,7 @@ endpoints! {
     LOCK =>                    "api/lock/",
     MARKNSFW =>                "api/marknsfw/",
     ME =>                      "api/v1/me/",
-    MEDIA_ASSET =>             "api/media/asset.json/",
+    MEDIA_ASSET =>             "api/media/asset/",
     MENTIONS =>                "message/mentions/",
     MESSAGE =>                 "message/messages/#id/",
     MESSAGES =>                "message/messages/",
@@ -311,6 +384,7 @@ endpoints! {
     REPORT =>                  "api/report/",
     RULES =>                   "r/#subreddit/about/rules/",
     SAVE =>                    "api/save/",
+    SAVED_CATEGORIES =>        "api/saved_categories/",
     SEARCH =>                  "search/",
     SELECT_FLAIR =>            "r/#subreddit/api/selectflair/",
     SENDREPLIES =>             "api/sendreplies/",
@@ -328,6 +402,7 @@ endpoints! {
     SUBMISSION =>              "comments/#id/",
     SUBMISSION_REPLIES =>      "message/selfreply/",
     SUBMIT =>                  "api/submit/",
+    SUBMIT_GALLERY_POST =>     "api/submit_gallery_post/",
     SUBMIT_POLL_POST =>        "api/submit_poll_post/",
     SUBREDDIT =>               "r/#subreddit/",
     SUBREDDIT_TOP =>           "r/#subreddit/top/",
@@ -369,6 +444,8 @@ endpoints! {
     USER_ABOUT =>              "user/#user/about/",
     USER_SUBMITTED =>          "user/#user/submitted/",
     USER_COMMENTS =>           "user/#user/comments/",
+    USER_SAVED =>              "user/#user/saved/",
+    USER_HIDDEN =>             "user/#user/hidden/",
     USER_BY_FULLNAME =>        "/api/user_data_by_account_ids/",
     USER_FLAIR =>              "r/#subreddit/api/user_flair_v2",
     USERS_NEW =>               "users/new/",
@@ -0,0 +1,63 @@
+//! Errors created by snoo itself rather than the http layer.
+//!
+//! They are returned wrapped in an [io::Error] like everything else,
+//! use [SnooError::from_io] to get them back out.
+//!
+//! ```no_run
+//! # use snoo::{Reddit, SnooError};
+//! # async fn example(r: Reddit) -> std::io::Result<()> {
+//! # let mut post = r.post("t3_gkxqzq").await?;
+//! if let Err(e) = post.upvote().await {
+//!     if let Some(SnooError::Archived(name)) = SnooError::from_io(&e) {
+//!         println!("{} is archived", name);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use crate::models::PostRequirementViolation;
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+#[derive(Debug, Clone)]
+pub enum SnooError {
+    /// The thing with this fullname is archived and can no longer be changed.
+    Archived(String),
+    /// The post would be rejected by the subreddits post requirements.
+    PostRequirements(Vec<PostRequirementViolation>),
+}
+
+impl SnooError {
+    /// Gets the [SnooError] inside of an [io::Error], if it has one.
+    pub fn from_io(err: &io::Error) -> Option<&SnooError> {
+        err.get_ref()?.downcast_ref::<SnooError>()
+    }
+
+    fn kind(&self) -> io::ErrorKind {
+        match self {
+            SnooError::Archived(_) => io::ErrorKind::PermissionDenied,
+            SnooError::PostRequirements(_) => io::ErrorKind::InvalidInput,
+        }
+    }
+}
+
+impl fmt::Display for SnooError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnooError::Archived(name) => write!(f, "{} is archived", name),
+            SnooError::PostRequirements(violations) => {
+                let reasons: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+                write!(f, "post does not meet the subreddit requirements: {}", reasons.join("; "))
+            }
+        }
+    }
+}
+
+impl Error for SnooError {}
+
+impl From<SnooError> for io::Error {
+    fn from(err: SnooError) -> io::Error {
+        io::Error::new(err.kind(), err)
+    }
+}
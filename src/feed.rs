@@ -1,6 +1,9 @@
 //! Pools reddit api and returns new items
 //!
-//! ```
+//! ```no_run
+//! # use snoo::Reddit;
+//! # use tokio::time::Duration;
+//! # async fn example() -> std::io::Result<()> {
 //! let r = Reddit::new()?;
 //! let all = r.subreddit("all");
 //!
@@ -8,30 +11,49 @@
 //! let mut rx = feed.start()?;
 //!
 //! while let Some(d) = rx.recv().await {
-//!     println!("{} \t {}", d.created, d.name);
+//!     println!("{} \t {}", d.created, d.moderate_data.name);
 //! }
-//!
+//! # Ok(())
+//! # }
 //! ```
 use crate::reddit::Reddit;
 
-use crate::endpoints::{Endpoint, SearchSort};
-use crate::models::{RedditResponseGeneric, SearchInfo};
+use crate::endpoints::{self, Endpoint};
+use crate::items::modmail::{Modmail, ModmailOptions};
+use crate::models::{
+    ListingChild, ModmailConversationInfo, ModmailConversationResponse, ModmailConversations,
+    ModmailMessage, ModmailSort, ModmailState, ModmailUnreadCount, RedditResponseGeneric,
+    TaggedListing,
+};
 
+use std::collections::HashSet;
 use std::io;
 
+use serde_json::Value;
 use std::marker::PhantomData;
 use tokio::sync::mpsc;
 use tokio::time::{delay_for, Duration};
 
-use serde::de::DeserializeOwned;
+/// Number of items requested each time the feed is polled.
+const FEED_PAGE_SIZE: &str = "100";
 
-pub trait Feedable: Clone + Send + Sync + 'static {
+/// Once this many ids have been seen only the latest page is kept.
+const FEED_MAX_SEEN: usize = 10_000;
+
+pub trait Feedable: ListingChild + Clone + Send + Sync + 'static {
     fn feed_id(&self) -> String;
+
+    /// What a [ContentStream] remembers to know an item has already been sent.
+    /// Defaults to [Feedable::feed_id] so each item is sent once, items that can
+    /// change while they stay in a listing include that state so they are sent again.
+    fn feed_key(&self) -> String {
+        self.feed_id()
+    }
 }
 
 pub struct ContentStream<T>
 where
-    T: Feedable + DeserializeOwned,
+    T: Feedable,
 {
     phantom: PhantomData<T>,
     reddit: Reddit,
@@ -41,12 +63,12 @@ where
 
 impl<T> ContentStream<T>
 where
-    T: Feedable + DeserializeOwned,
+    T: Feedable,
 {
     pub fn new(reddit: Reddit, search_ep: Endpoint) -> ContentStream<T> {
         ContentStream {
             phantom: PhantomData,
-            reddit: reddit,
+            reddit,
             endpoint: search_ep,
             delay: Duration::from_secs(3),
         }
@@ -59,53 +81,53 @@ where
         self
     }
 
+    async fn fetch(&self) -> io::Result<Vec<T>> {
+        let ep = self
+            .endpoint
+            .clone()
+            .add_query_pairs(&[("limit", FEED_PAGE_SIZE)]);
+
+        let listing = self
+            .reddit
+            .api
+            .get_api::<RedditResponseGeneric<TaggedListing<Value>>>(ep.to_url())
+            .await?
+            .data;
+        Ok(T::from_children(listing.children).0)
+    }
+
+    /// Polls the endpoint and sends any items that have not been seen before,
+    /// compared by [Feedable::feed_key].
+    /// Items already in the listing when the feed starts are skipped,
+    /// as are items that can not be parsed.
     async fn read_feed(self, mut tx: mpsc::Sender<T>) -> io::Result<()> {
-        let mut newest_item = {
-            let endpoint = self
-                .endpoint
-                .clone()
-                .filter(None, SearchSort::New, None, None);
-            //Yikes
-            self.reddit
-                .api
-                .get_api::<RedditResponseGeneric<SearchInfo<T>>>(endpoint.to_url())
-                .await?
-                .data
-                .results
-                .children
-                .iter()
-                .nth(0)
-                .map(|e| e.data.feed_id())
-        };
+        let mut seen: HashSet<String> = self.fetch().await?.iter().map(T::feed_key).collect();
 
         loop {
             delay_for(self.delay).await;
-            let before = if let Some(e) = &newest_item {
-                Some(e.as_str())
-            } else {
-                None
+
+            // Try again next poll if reddit fails.
+            let items = match self.fetch().await {
+                Ok(items) => items,
+                Err(_) => continue,
             };
 
-            let ep = self
-                .endpoint
-                .clone()
-                .filter(None, SearchSort::New, before, None);
+            let page_keys: Vec<String> = items.iter().map(T::feed_key).collect();
+            let new_items: Vec<T> = items
+                .into_iter()
+                .zip(page_keys.iter())
+                .filter(|(_, key)| !seen.contains(*key))
+                .map(|(item, _)| item)
+                .collect();
 
-            let search = self
-                .reddit
-                .api
-                .get_api::<RedditResponseGeneric<SearchInfo<T>>>(ep.to_url())
-                .await
-                .unwrap()
-                .data
-                .results
-                .inner_children();
-            if search.len() > 0 {
-                newest_item = Some(search[0].feed_id().clone());
+            seen.extend(page_keys.iter().cloned());
+            if seen.len() > FEED_MAX_SEEN {
+                seen = page_keys.into_iter().collect();
             }
 
-            for item in search.iter().rev() {
-                tx.send(item.clone())
+            // Listings are newest first.
+            for item in new_items.into_iter().rev() {
+                tx.send(item)
                     .await
                     .map_err(|_| io::Error::new(io::ErrorKind::ConnectionReset, ""))?;
             }
@@ -119,3 +141,312 @@ where
         Ok(rx)
     }
 }
+
+/// Something new in modmail, found by a [ModmailStream]
+#[derive(Debug, Clone)]
+pub enum ModmailEvent {
+    /// A conversation that has not been seen before,
+    /// with the messages it was listed with.
+    NewConversation {
+        conversation: ModmailConversationInfo,
+        messages: Vec<ModmailMessage>,
+    },
+    /// A new message in a conversation that has been seen before.
+    NewMessage {
+        conversation: ModmailConversationInfo,
+        message: ModmailMessage,
+    },
+}
+
+impl ModmailEvent {
+    pub fn conversation(&self) -> &ModmailConversationInfo {
+        match self {
+            ModmailEvent::NewConversation { conversation, .. } => conversation,
+            ModmailEvent::NewMessage { conversation, .. } => conversation,
+        }
+    }
+}
+
+/// Polls modmail across every moderated subreddit and returns new conversations and messages.
+///
+/// The unread counts are checked each poll and conversations are only listed when they
+/// change, or every `full_refresh` polls to catch replies that do not change the counts.
+///
+/// ```no_run
+/// # use snoo::Reddit;
+/// # use tokio::time::Duration;
+/// # async fn example(r: Reddit) -> std::io::Result<()> {
+/// let mut rx = r.modmail().stream().delay(Duration::from_secs(10)).start()?;
+/// while let Some(event) = rx.recv().await {
+///     println!("{:?}", event);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct ModmailStream {
+    reddit: Reddit,
+    delay: Duration,
+    full_refresh: u32,
+    mark_read: bool,
+}
+
+/// What a [ModmailStream] has already sent.
+#[derive(Default)]
+struct ModmailSeen {
+    conversations: HashSet<String>,
+    messages: HashSet<String>,
+}
+
+impl ModmailSeen {
+    fn insert(&mut self, conversation: &ModmailConversationInfo) {
+        self.messages
+            .extend(conversation.message_ids().map(str::to_owned));
+        self.conversations.insert(conversation.id.clone());
+    }
+
+    /// Keeps only the conversations and messages that are still listed.
+    fn trim(&mut self, page: &[(ModmailConversationInfo, Vec<ModmailMessage>)]) {
+        if self.messages.len() <= FEED_MAX_SEEN && self.conversations.len() <= FEED_MAX_SEEN {
+            return;
+        }
+        *self = ModmailSeen::default();
+        for (conversation, _) in page {
+            self.insert(conversation);
+        }
+    }
+}
+
+impl ModmailStream {
+    pub fn new(reddit: Reddit) -> ModmailStream {
+        ModmailStream {
+            reddit,
+            delay: Duration::from_secs(10),
+            full_refresh: 6,
+            mark_read: false,
+        }
+    }
+
+    /// delay between polling the api
+    /// default: 10s
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Number of polls between listing conversations
+    /// even if the unread counts have not changed.
+    /// default: 6
+    pub fn full_refresh(mut self, polls: u32) -> Self {
+        self.full_refresh = polls.max(1);
+        self
+    }
+
+    /// Mark conversations as read once their new messages have been sent.
+    /// default: false
+    pub fn mark_read(mut self, mark_read: bool) -> Self {
+        self.mark_read = mark_read;
+        self
+    }
+
+    async fn unread_count(&self) -> io::Result<ModmailUnreadCount> {
+        Modmail::new(&self.reddit).unread_count().await
+    }
+
+    /// The most recently updated conversations, oldest first.
+    async fn fetch(&self) -> io::Result<Vec<(ModmailConversationInfo, Vec<ModmailMessage>)>> {
+        let ep = ModmailOptions::new()
+            .state(ModmailState::All)
+            .sort(ModmailSort::Recent)
+            .limit(100)
+            .apply(self.reddit.ep(endpoints::MODMAIL_CONVERSATIONS)?);
+
+        let resp = self
+            .reddit
+            .api
+            .get_api::<ModmailConversations>(ep.to_url())
+            .await?;
+
+        let mut page = resp.into_ordered();
+        page.reverse();
+        Ok(page)
+    }
+
+    /// Messages in `conversation` that have not been sent yet, oldest first.
+    /// Only the latest message is listed with a conversation,
+    /// so the whole conversation is loaded if there are more.
+    async fn new_messages(
+        &self,
+        conversation: &ModmailConversationInfo,
+        mut listed: Vec<ModmailMessage>,
+        seen: &ModmailSeen,
+    ) -> io::Result<Vec<ModmailMessage>> {
+        let unseen: Vec<&str> = conversation
+            .message_ids()
+            .filter(|id| !seen.messages.contains(*id))
+            .collect();
+
+        if unseen.iter().any(|id| !listed.iter().any(|m| m.id == *id)) {
+            let ep = self
+                .reddit
+                .ep(endpoints::MODMAIL_CONVERSATION.id(&conversation.id))?
+                .add_query_pairs(&[("markRead", "false")]);
+            let (_, messages) = self
+                .reddit
+                .api
+                .get_api::<ModmailConversationResponse>(ep.to_url())
+                .await?
+                .into_parts();
+            listed = messages;
+        }
+
+        listed.retain(|m| unseen.contains(&m.id.as_str()));
+        Ok(listed)
+    }
+
+    async fn read_feed(self, mut tx: mpsc::Sender<ModmailEvent>) -> io::Result<()> {
+        let mut seen = ModmailSeen::default();
+        let mut last_count = self.unread_count().await?;
+        for (conversation, _) in self.fetch().await? {
+            seen.insert(&conversation);
+        }
+
+        let mut polls = 0;
+        loop {
+            delay_for(self.delay).await;
+            polls += 1;
+
+            // Try again next poll if reddit fails.
+            let count = match self.unread_count().await {
+                Ok(count) => count,
+                Err(_) => continue,
+            };
+            if count == last_count && polls % self.full_refresh != 0 {
+                continue;
+            }
+
+            let page = match self.fetch().await {
+                Ok(page) => page,
+                Err(_) => continue,
+            };
+            last_count = count;
+
+            let mut events = Vec::new();
+            for (conversation, listed) in page.iter().cloned() {
+                if !seen.conversations.contains(&conversation.id) {
+                    seen.insert(&conversation);
+                    events.push(ModmailEvent::NewConversation {
+                        conversation,
+                        messages: listed,
+                    });
+                    continue;
+                }
+
+                let messages = match self.new_messages(&conversation, listed, &seen).await {
+                    Ok(messages) => messages,
+                    Err(_) => continue,
+                };
+                seen.insert(&conversation);
+                for message in messages {
+                    events.push(ModmailEvent::NewMessage {
+                        conversation: conversation.clone(),
+                        message,
+                    });
+                }
+            }
+            seen.trim(&page);
+
+            let mut read = Vec::new();
+            for event in events {
+                let conversation = event.conversation();
+                if conversation.is_unread() && !read.contains(&conversation.id) {
+                    read.push(conversation.id.clone());
+                }
+
+                tx.send(event)
+                    .await
+                    .map_err(|_| io::Error::new(io::ErrorKind::ConnectionReset, ""))?;
+            }
+
+            if self.mark_read && !read.is_empty() {
+                let ids: Vec<&str> = read.iter().map(String::as_str).collect();
+                // Marking read is best effort, the messages have already been sent.
+                let _ = Modmail::new(&self.reddit).mark_read(&ids).await;
+            }
+        }
+    }
+
+    /// start polling modmail and return the new conversations and messages.
+    pub fn start(self) -> io::Result<mpsc::Receiver<ModmailEvent>> {
+        let (tx, rx) = mpsc::channel(10);
+        tokio::spawn(async { self.read_feed(tx).await });
+        Ok(rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn conversation(id: &str, message_ids: &[&str]) -> ModmailConversationInfo {
+        let obj_ids: Vec<Value> = message_ids
+            .iter()
+            .map(|m| json!({"id": m, "key": "messages"}))
+            .chain(Some(json!({"id": "action", "key": "modActions"})))
+            .collect();
+        serde_json::from_value(json!({
+            "id": id,
+            "subject": "A conversation",
+            "lastUpdated": "2020-05-15T12:00:00.000000+00:00",
+            "lastModUpdate": null,
+            "lastUserUpdate": null,
+            "lastUnread": null,
+            "objIds": obj_ids,
+            "owner": {"displayName": "rust", "id": "t5_2s7lj", "type": "subreddit"},
+            "participant": null,
+            "state": 1,
+            "conversationType": "sr_user",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn modmail_seen_insert() {
+        let mut seen = ModmailSeen::default();
+        seen.insert(&conversation("c1", &["m1", "m2"]));
+        seen.insert(&conversation("c1", &["m1", "m2", "m3"]));
+        seen.insert(&conversation("c2", &["m4"]));
+
+        assert_eq!(seen.conversations.len(), 2);
+        assert!(seen.conversations.contains("c1") && seen.conversations.contains("c2"));
+        assert_eq!(seen.messages.len(), 4);
+        // Mod actions are not messages.
+        assert!(!seen.messages.contains("action"));
+    }
+
+    #[test]
+    fn modmail_seen_trim() {
+        let page = vec![
+            (conversation("c1", &["m1"]), Vec::new()),
+            (conversation("c2", &["m2", "m3"]), Vec::new()),
+        ];
+
+        let mut seen = ModmailSeen::default();
+        seen.insert(&conversation("old", &["m0"]));
+        for (conversation, _) in &page {
+            seen.insert(conversation);
+        }
+        seen.trim(&page);
+        // Nothing is dropped until the limit is reached.
+        assert!(seen.conversations.contains("old"));
+
+        seen.messages
+            .extend((0..=FEED_MAX_SEEN).map(|i| format!("extra{}", i)));
+        seen.trim(&page);
+        assert_eq!(seen.conversations.len(), 2);
+        assert!(!seen.conversations.contains("old"));
+        let mut messages: Vec<&str> = seen.messages.iter().map(String::as_str).collect();
+        messages.sort();
+        assert_eq!(messages, ["m1", "m2", "m3"]);
+    }
+}
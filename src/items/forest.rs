@@ -0,0 +1,750 @@
+//! Comment trees where the comments reddit did not send
+//! are kept as [MoreComments] so they can be loaded later.
+use crate::endpoints::{self, CommentSort};
+use crate::items::submission::{Comment, SubmissionOptions};
+use crate::models::{CommentNode, MoreComments, ThingsResponse};
+use crate::reddit::Reddit;
+
+use regex::Regex;
+use std::collections::{HashSet, VecDeque};
+use std::io;
+
+/// Max number of comment ids `api/morechildren` accepts in one request.
+const MORE_CHILDREN_BATCH_SIZE: usize = 100;
+
+/// The replies to a submission or comment.
+pub struct CommentForest<'r> {
+    reddit: &'r Reddit,
+    link_id: String,
+    parent_id: String,
+    sort: Option<CommentSort>,
+    pub(crate) comments: Vec<Comment<'r>>,
+    pub(crate) more: Vec<MoreComments>,
+    orphans: Vec<CommentNode>,
+}
+
+impl<'r> CommentForest<'r> {
+    /// Builds the tree of replies to `parent_id` (the submission or a comment).
+    ///
+    /// Nodes are placed under their parent by `parent_id`, so flat listings
+    /// (`threaded=false`) build the same tree as nested ones. A node whose parent
+    /// was not sent, e.g. the focused comment of a thread, is kept at the top level.
+    pub(crate) fn new(
+        reddit: &'r Reddit,
+        link_id: &str,
+        parent_id: &str,
+        sort: Option<CommentSort>,
+        nodes: Vec<CommentNode>,
+    ) -> Self {
+        let mut forest = CommentForest {
+            reddit,
+            link_id: link_id.to_owned(),
+            parent_id: parent_id.to_owned(),
+            sort,
+            comments: Vec::new(),
+            more: Vec::new(),
+            orphans: Vec::new(),
+        };
+
+        let mut pending = nodes;
+        while !pending.is_empty() {
+            let before = pending.len();
+            let mut rest = forest.attach_all(pending);
+            if rest.len() == before {
+                let root = missing_parent(&rest);
+                forest.push(rest.remove(root));
+            }
+            pending = rest;
+        }
+        forest
+    }
+
+    /// The top level comments.
+    pub fn comments(&self) -> &[Comment<'r>] {
+        &self.comments
+    }
+
+    pub fn comments_mut(&mut self) -> &mut [Comment<'r>] {
+        &mut self.comments
+    }
+
+    /// Placeholders for top level comments that have not been loaded.
+    pub fn more(&self) -> &[MoreComments] {
+        &self.more
+    }
+
+    /// Fullname of the submission the comments are in.
+    pub fn link_id(&self) -> &str {
+        &self.link_id
+    }
+
+    /// The sort the comments were loaded with, also used for [CommentForest::load_more].
+    pub fn sort(&self) -> Option<CommentSort> {
+        self.sort
+    }
+
+    /// Comments and placeholders loaded by [CommentForest::load_more]
+    /// whose parent is not in the tree. They are moved into
+    /// the tree if their parent is loaded later.
+    pub fn orphans(&self) -> &[CommentNode] {
+        &self.orphans
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.comments.is_empty() && self.more.is_empty()
+    }
+
+    /// Number of [MoreComments] placeholders anywhere in the tree.
+    pub fn more_count(&self) -> usize {
+        self.more.len()
+            + self
+                .comments
+                .iter()
+                .map(|c| c.replies().more_count())
+                .sum::<usize>()
+    }
+
+    /// Walks every loaded comment depth first, in the order reddit shows them.
+    /// Top level comments have a depth of 0.
+    pub fn dfs<'a>(&'a self) -> DepthFirst<'a, 'r> {
+        DepthFirst::new(self.comments.iter().map(|c| (0, c)).collect())
+    }
+
+    /// Walks every loaded comment one level at a time.
+    /// Top level comments have a depth of 0.
+    pub fn bfs<'a>(&'a self) -> BreadthFirst<'a, 'r> {
+        BreadthFirst::new(self.comments.iter().map(|c| (0, c)).collect())
+    }
+
+    /// Every loaded comment in depth first order.
+    pub fn flatten(&self) -> Vec<&Comment<'r>> {
+        self.dfs().map(|(_, c)| c).collect()
+    }
+
+    /// Finds a loaded comment by its fullname (`t1_...`).
+    pub fn find(&self, name: &str) -> Option<&Comment<'r>> {
+        self.dfs().map(|(_, c)| c).find(|c| c.name() == name)
+    }
+
+    /// Depth of the comment with the fullname `name`.
+    pub fn depth_of(&self, name: &str) -> Option<usize> {
+        self.dfs().find(|(_, c)| c.name() == name).map(|(d, _)| d)
+    }
+
+    /// The comment `comment` is replying to.
+    /// None if it is a top level comment or the parent is not loaded.
+    pub fn parent_of(&self, comment: &Comment<'_>) -> Option<&Comment<'r>> {
+        self.find(comment.parent_id())
+    }
+
+    /// Every loaded comment matching `predicate`, in depth first order.
+    pub fn filter<F>(&self, mut predicate: F) -> Vec<&Comment<'r>>
+    where
+        F: FnMut(&Comment<'r>) -> bool,
+    {
+        self.dfs()
+            .map(|(_, c)| c)
+            .filter(|c| predicate(c))
+            .collect()
+    }
+
+    /// Every loaded comment posted by `author`.
+    pub fn by_author(&self, author: &str) -> Vec<&Comment<'r>> {
+        self.filter(|c| c.info().author.eq_ignore_ascii_case(author))
+    }
+
+    /// Every loaded comment with a body matching `pattern`.
+    pub fn matching(&self, pattern: &Regex) -> Vec<&Comment<'r>> {
+        self.filter(|c| pattern.is_match(c.body()))
+    }
+
+    /// Loads the comments behind the [MoreComments] placeholders
+    /// and adds them into the tree.
+    ///
+    /// Each request loads up to 100 comments. Loading stops when there are
+    /// no placeholders left or `request_limit` requests have been made.
+    /// Loaded comments whose parent is not in the tree are kept in [CommentForest::orphans].
+    /// Returns the number of placeholders still in the tree.
+    pub async fn load_more(&mut self, request_limit: Option<usize>) -> io::Result<usize> {
+        let mut planner = MorePlanner::new(request_limit);
+
+        while let Some(more) = self.take_more() {
+            match planner.plan(more) {
+                MoreRequest::Skip => {}
+                MoreRequest::Stop(more) => {
+                    self.insert_all(vec![CommentNode::More(more)]);
+                    break;
+                }
+                MoreRequest::Thread(more) => {
+                    if let Err(e) = self.load_thread(&more.parent_id).await {
+                        self.insert_all(vec![CommentNode::More(more)]);
+                        return Err(e);
+                    }
+                }
+                MoreRequest::Batch(more, batch_len) => {
+                    let mut nodes = match self.fetch_more(&more.children[..batch_len]).await {
+                        Ok(nodes) => nodes,
+                        Err(e) => {
+                            self.insert_all(vec![CommentNode::More(more)]);
+                            return Err(e);
+                        }
+                    };
+                    nodes.extend(remove_batch(more, batch_len).map(CommentNode::More));
+                    self.insert_all(nodes);
+                }
+            }
+        }
+
+        Ok(self.more_count())
+    }
+
+    async fn fetch_more(&self, ids: &[String]) -> io::Result<Vec<CommentNode>> {
+        let mut ep = self.reddit.ep(endpoints::MORECHILDREN)?.add_query_pairs(&[
+            ("api_type", "json"),
+            ("link_id", &self.link_id),
+            ("children", &ids.join(",")),
+            ("limit_children", "false"),
+        ]);
+        if let Some(sort) = self.sort {
+            ep = ep.add_query_pairs(&[("sort", sort.to_str())]);
+        }
+
+        let resp = self
+            .reddit
+            .api
+            .get_json_api::<ThingsResponse<CommentNode>>(ep.to_url())
+            .await?;
+        Ok(resp.things)
+    }
+
+    /// Loads a "continue this thread" placeholder by
+    /// loading the submission focused on the parent comment.
+    async fn load_thread(&mut self, parent_name: &str) -> io::Result<()> {
+        let link_id = self.link_id.trim_start_matches("t3_");
+        let mut options = SubmissionOptions::new().comment(parent_name);
+        if let Some(sort) = self.sort {
+            options = options.sort(sort);
+        }
+        let mut thread = self.reddit.submission_with(link_id, &options).await?;
+
+        let loaded = thread
+            .forest_mut()
+            .comments
+            .drain(..)
+            .find(|c| c.name() == parent_name);
+        if let (Some(mut loaded), Some(parent)) = (loaded, self.find_mut(parent_name)) {
+            let replies = parent.replies_mut();
+            replies.comments.append(&mut loaded.replies_mut().comments);
+            replies.more.append(&mut loaded.replies_mut().more);
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, node: CommentNode) {
+        match node {
+            CommentNode::Comment(c) => {
+                let mut comment = self.reddit.bind::<Comment>(*c);
+                comment.replies_mut().set_sort(self.sort);
+                self.comments.push(comment);
+            }
+            CommentNode::More(m) => self.more.push(m),
+        }
+    }
+
+    fn set_sort(&mut self, sort: Option<CommentSort>) {
+        self.sort = sort;
+        for comment in self.comments.iter_mut() {
+            comment.replies_mut().set_sort(sort);
+        }
+    }
+
+    /// Adds a node under its parent, giving it back if the parent is not in the tree.
+    fn attach(&mut self, node: CommentNode) -> Result<(), CommentNode> {
+        if node.parent_id() == self.parent_id {
+            self.push(node);
+            return Ok(());
+        }
+
+        match self.find_mut(node.parent_id()) {
+            Some(parent) => {
+                parent.replies_mut().push(node);
+                Ok(())
+            }
+            None => Err(node),
+        }
+    }
+
+    /// Attaches as many of `nodes` as possible, in any order.
+    /// Returns the nodes whose parents are not in the tree.
+    fn attach_all(&mut self, nodes: Vec<CommentNode>) -> Vec<CommentNode> {
+        let mut pending = nodes;
+        loop {
+            let before = pending.len();
+            pending = pending
+                .into_iter()
+                .filter_map(|n| self.attach(n).err())
+                .collect();
+            if pending.is_empty() || pending.len() == before {
+                return pending;
+            }
+        }
+    }
+
+    /// Adds loaded nodes into the tree, retrying the orphans
+    /// in case their parents were loaded.
+    fn insert_all(&mut self, nodes: Vec<CommentNode>) {
+        let mut pending = std::mem::take(&mut self.orphans);
+        pending.extend(nodes);
+        self.orphans = self.attach_all(pending);
+    }
+
+    fn find_mut(&mut self, name: &str) -> Option<&mut Comment<'r>> {
+        for comment in self.comments.iter_mut() {
+            if comment.name() == name {
+                return Some(comment);
+            }
+            if let Some(found) = comment.replies_mut().find_mut(name) {
+                return Some(found);
+            }
+        }
+        None
+    }
+
+    /// Removes the first placeholder in the tree.
+    fn take_more(&mut self) -> Option<MoreComments> {
+        if !self.more.is_empty() {
+            return Some(self.more.remove(0));
+        }
+        self.comments
+            .iter_mut()
+            .find_map(|c| c.replies_mut().take_more())
+    }
+}
+
+/// Index of a node in `nodes` whose parent is not one of `nodes`.
+fn missing_parent(nodes: &[CommentNode]) -> usize {
+    let names: HashSet<&str> = nodes
+        .iter()
+        .filter_map(|n| match n {
+            CommentNode::Comment(c) => Some(c.moderate_data.name.as_str()),
+            CommentNode::More(_) => None,
+        })
+        .collect();
+    nodes
+        .iter()
+        .position(|n| !names.contains(n.parent_id()))
+        .unwrap_or(0)
+}
+
+/// What [CommentForest::load_more] should do with a placeholder.
+#[derive(Debug)]
+enum MoreRequest {
+    /// All of its comments have already been requested.
+    Skip,
+    /// The request limit was reached.
+    Stop(MoreComments),
+    /// Load the thread below the placeholders parent.
+    Thread(MoreComments),
+    /// Request the first `n` of its children.
+    Batch(MoreComments, usize),
+}
+
+/// Splits placeholders into `api/morechildren` requests.
+struct MorePlanner {
+    request_limit: Option<usize>,
+    requests: usize,
+    requested: HashSet<String>,
+}
+
+impl MorePlanner {
+    fn new(request_limit: Option<usize>) -> Self {
+        Self {
+            request_limit,
+            requests: 0,
+            requested: HashSet::new(),
+        }
+    }
+
+    fn plan(&mut self, mut more: MoreComments) -> MoreRequest {
+        let continue_thread = more.is_continue_thread();
+        if continue_thread {
+            if self.requested.contains(&more.parent_id) {
+                return MoreRequest::Skip;
+            }
+        } else {
+            let requested = &self.requested;
+            more.children.retain(|id| !requested.contains(id));
+            if more.children.is_empty() {
+                return MoreRequest::Skip;
+            }
+        }
+
+        if self
+            .request_limit
+            .map(|l| self.requests >= l)
+            .unwrap_or(false)
+        {
+            return MoreRequest::Stop(more);
+        }
+        self.requests += 1;
+
+        if continue_thread {
+            self.requested.insert(more.parent_id.clone());
+            return MoreRequest::Thread(more);
+        }
+
+        let batch_len = more.children.len().min(MORE_CHILDREN_BATCH_SIZE);
+        self.requested
+            .extend(more.children[..batch_len].iter().cloned());
+        MoreRequest::Batch(more, batch_len)
+    }
+}
+
+/// What is left of a placeholder once the first `batch_len` children are loaded.
+fn remove_batch(mut more: MoreComments, batch_len: usize) -> Option<MoreComments> {
+    more.children.drain(..batch_len);
+    if more.children.is_empty() {
+        return None;
+    }
+    more.count = more.count.saturating_sub(batch_len as i32);
+    Some(more)
+}
+
+/// Depth first iterator over a comment tree.
+/// Yields each comment with its depth.
+pub struct DepthFirst<'a, 'r> {
+    stack: Vec<(usize, &'a Comment<'r>)>,
+}
+
+impl<'a, 'r> DepthFirst<'a, 'r> {
+    pub(crate) fn new(mut roots: Vec<(usize, &'a Comment<'r>)>) -> Self {
+        roots.reverse();
+        Self { stack: roots }
+    }
+}
+
+impl<'a, 'r> Iterator for DepthFirst<'a, 'r> {
+    type Item = (usize, &'a Comment<'r>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, comment) = self.stack.pop()?;
+        self.stack.extend(
+            comment
+                .replies()
+                .comments
+                .iter()
+                .rev()
+                .map(|c| (depth + 1, c)),
+        );
+        Some((depth, comment))
+    }
+}
+
+/// Breadth first iterator over a comment tree.
+/// Yields each comment with its depth.
+pub struct BreadthFirst<'a, 'r> {
+    queue: VecDeque<(usize, &'a Comment<'r>)>,
+}
+
+impl<'a, 'r> BreadthFirst<'a, 'r> {
+    pub(crate) fn new(roots: Vec<(usize, &'a Comment<'r>)>) -> Self {
+        Self {
+            queue: roots.into(),
+        }
+    }
+}
+
+impl<'a, 'r> Iterator for BreadthFirst<'a, 'r> {
+    type Item = (usize, &'a Comment<'r>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, comment) = self.queue.pop_front()?;
+        self.queue
+            .extend(comment.replies().comments.iter().map(|c| (depth + 1, c)));
+        Some((depth, comment))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures::comment_json;
+    use serde_json::json;
+
+    const LINK: &str = "t3_link";
+
+    fn comment(id: &str, parent: &str, replies: Vec<CommentNode>) -> CommentNode {
+        let replies = if replies.is_empty() {
+            json!("")
+        } else {
+            json!({"kind": "Listing", "data": {
+                "children": replies, "after": null, "before": null, "modhash": null, "dist": null
+            }})
+        };
+        serde_json::from_value(comment_json(id, parent, LINK, json!({"replies": replies}))).unwrap()
+    }
+
+    fn more(parent: &str, children: &[&str]) -> MoreComments {
+        MoreComments {
+            count: children.len() as i32,
+            name: "t1__".to_owned(),
+            id: "_".to_owned(),
+            parent_id: parent.to_owned(),
+            depth: 0,
+            children: children.iter().map(|c| (*c).to_owned()).collect(),
+        }
+    }
+
+    fn ids(n: usize) -> Vec<String> {
+        (0..n).map(|i| format!("c{}", i)).collect()
+    }
+
+    /// Each comment as `depth:name`, depth first.
+    fn shape(forest: &CommentForest<'_>) -> Vec<String> {
+        forest
+            .dfs()
+            .map(|(d, c)| format!("{}:{}", d, c.name().trim_start_matches("t1_")))
+            .collect()
+    }
+
+    fn forest<'r>(reddit: &'r Reddit, nodes: Vec<CommentNode>) -> CommentForest<'r> {
+        CommentForest::new(reddit, LINK, LINK, None, nodes)
+    }
+
+    #[test]
+    fn builds_tree_from_nested_nodes() {
+        let reddit = Reddit::new().unwrap();
+        let nodes = vec![
+            comment(
+                "a",
+                LINK,
+                vec![comment("b", "t1_a", vec![comment("c", "t1_b", vec![])])],
+            ),
+            comment("d", LINK, vec![]),
+            CommentNode::More(more(LINK, &["e", "f"])),
+        ];
+
+        let forest = forest(&reddit, nodes);
+        assert_eq!(shape(&forest), ["0:a", "1:b", "2:c", "0:d"]);
+        assert_eq!(forest.more().len(), 1);
+        assert!(forest.orphans().is_empty());
+    }
+
+    #[test]
+    fn builds_tree_from_flat_nodes() {
+        let reddit = Reddit::new().unwrap();
+        let nodes = vec![
+            comment("a", LINK, vec![]),
+            comment("b", "t1_a", vec![]),
+            comment("c", "t1_b", vec![]),
+            CommentNode::More(more("t1_b", &["x"])),
+            comment("d", LINK, vec![]),
+            comment("e", "t1_d", vec![]),
+        ];
+
+        let forest = forest(&reddit, nodes);
+        assert_eq!(shape(&forest), ["0:a", "1:b", "2:c", "0:d", "1:e"]);
+        assert_eq!(forest.find("t1_b").unwrap().replies().more().len(), 1);
+        assert_eq!(forest.more_count(), 1);
+    }
+
+    #[test]
+    fn builds_tree_when_children_come_first() {
+        let reddit = Reddit::new().unwrap();
+        let nodes = vec![
+            comment("c", "t1_b", vec![]),
+            comment("b", "t1_a", vec![]),
+            comment("a", LINK, vec![]),
+        ];
+
+        let forest = forest(&reddit, nodes);
+        assert_eq!(shape(&forest), ["0:a", "1:b", "2:c"]);
+    }
+
+    #[test]
+    fn focused_thread_keeps_root_without_parent() {
+        let reddit = Reddit::new().unwrap();
+        let nodes = vec![
+            comment("b", "t1_a", vec![]),
+            comment("c", "t1_b", vec![]),
+            comment("d", "t1_c", vec![]),
+        ];
+
+        let forest = forest(&reddit, nodes);
+        assert_eq!(shape(&forest), ["0:b", "1:c", "2:d"]);
+    }
+
+    #[test]
+    fn reply_forest_uses_comment_as_root() {
+        let reddit = Reddit::new().unwrap();
+        let nodes = vec![comment("b", "t1_a", vec![]), comment("c", "t1_b", vec![])];
+
+        let forest = CommentForest::new(&reddit, LINK, "t1_a", None, nodes);
+        assert_eq!(shape(&forest), ["0:b", "1:c"]);
+    }
+
+    #[test]
+    fn loaded_nodes_without_parent_are_orphans() {
+        let reddit = Reddit::new().unwrap();
+        let mut forest = forest(&reddit, vec![comment("a", LINK, vec![])]);
+
+        forest.insert_all(vec![
+            comment("b", "t1_a", vec![]),
+            comment("y", "t1_x", vec![]),
+            CommentNode::More(more("t1_x", &["z"])),
+        ]);
+        assert_eq!(shape(&forest), ["0:a", "1:b"]);
+        assert_eq!(forest.orphans().len(), 2);
+        assert_eq!(forest.more_count(), 0);
+
+        // Adopted once the parent is loaded.
+        forest.insert_all(vec![comment("x", "t1_b", vec![])]);
+        assert_eq!(shape(&forest), ["0:a", "1:b", "2:x", "3:y"]);
+        assert!(forest.orphans().is_empty());
+        assert_eq!(forest.more_count(), 1);
+    }
+
+    #[test]
+    fn sort_is_passed_to_replies() {
+        let reddit = Reddit::new().unwrap();
+        let nodes = vec![comment("a", LINK, vec![comment("b", "t1_a", vec![])])];
+        let mut forest = CommentForest::new(&reddit, LINK, LINK, Some(CommentSort::New), nodes);
+        forest.insert_all(vec![comment("c", "t1_b", vec![])]);
+
+        let a = forest.find("t1_a").unwrap();
+        assert_eq!(a.replies().sort(), Some(CommentSort::New));
+        let c = forest.find("t1_c").unwrap();
+        assert_eq!(c.replies().sort(), Some(CommentSort::New));
+    }
+
+    #[test]
+    fn load_more_batches_by_100() {
+        let mut planner = MorePlanner::new(None);
+        let mut placeholder = more(LINK, &[]);
+        placeholder.children = ids(250);
+        placeholder.count = 250;
+
+        let mut batches = Vec::new();
+        let mut next = Some(placeholder);
+        while let Some(placeholder) = next.take() {
+            match planner.plan(placeholder) {
+                MoreRequest::Batch(placeholder, len) => {
+                    batches.push(placeholder.children[..len].to_vec());
+                    next = remove_batch(placeholder, len);
+                    if let Some(rest) = &next {
+                        assert_eq!(rest.count as usize, rest.children.len());
+                    }
+                }
+                other => panic!("unexpected {:?}", other),
+            }
+        }
+
+        assert_eq!(
+            batches.iter().map(Vec::len).collect::<Vec<_>>(),
+            [100, 100, 50]
+        );
+        assert_eq!(batches.concat(), ids(250));
+    }
+
+    #[test]
+    fn load_more_respects_request_limit() {
+        let mut planner = MorePlanner::new(Some(2));
+        let mut placeholder = more(LINK, &[]);
+        placeholder.children = ids(250);
+
+        let placeholder = match planner.plan(placeholder) {
+            MoreRequest::Batch(p, len) => remove_batch(p, len).unwrap(),
+            other => panic!("unexpected {:?}", other),
+        };
+        assert!(matches!(
+            planner.plan(more("t1_a", &[])),
+            MoreRequest::Thread(_)
+        ));
+
+        match planner.plan(placeholder) {
+            MoreRequest::Stop(p) => assert_eq!(p.children.len(), 150),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn load_more_skips_requested_ids() {
+        let mut planner = MorePlanner::new(None);
+        assert!(matches!(
+            planner.plan(more(LINK, &["a", "b"])),
+            MoreRequest::Batch(_, 2)
+        ));
+
+        match planner.plan(more("t1_x", &["b", "c"])) {
+            MoreRequest::Batch(p, 1) => assert_eq!(p.children, ["c"]),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(matches!(
+            planner.plan(more("t1_y", &["a", "c"])),
+            MoreRequest::Skip
+        ));
+
+        assert!(matches!(
+            planner.plan(more("t1_z", &[])),
+            MoreRequest::Thread(_)
+        ));
+        assert!(matches!(planner.plan(more("t1_z", &[])), MoreRequest::Skip));
+    }
+
+    /// a ─ b ─ c
+    ///   └ d
+    /// e ─ f
+    fn sample_forest(reddit: &Reddit) -> CommentForest<'_> {
+        forest(
+            reddit,
+            vec![
+                comment(
+                    "a",
+                    LINK,
+                    vec![
+                        comment("b", "t1_a", vec![comment("c", "t1_b", vec![])]),
+                        comment("d", "t1_a", vec![]),
+                    ],
+                ),
+                comment("e", LINK, vec![comment("f", "t1_e", vec![])]),
+            ],
+        )
+    }
+
+    #[test]
+    fn dfs_order() {
+        let reddit = Reddit::new().unwrap();
+        let forest = sample_forest(&reddit);
+        assert_eq!(shape(&forest), ["0:a", "1:b", "2:c", "1:d", "0:e", "1:f"]);
+    }
+
+    #[test]
+    fn bfs_order() {
+        let reddit = Reddit::new().unwrap();
+        let forest = sample_forest(&reddit);
+        let order: Vec<_> = forest
+            .bfs()
+            .map(|(d, c)| format!("{}:{}", d, c.name().trim_start_matches("t1_")))
+            .collect();
+        assert_eq!(order, ["0:a", "0:e", "1:b", "1:d", "1:f", "2:c"]);
+    }
+
+    #[test]
+    fn flatten_and_find() {
+        let reddit = Reddit::new().unwrap();
+        let forest = sample_forest(&reddit);
+
+        let names: Vec<_> = forest.flatten().iter().map(|c| c.name()).collect();
+        assert_eq!(names, ["t1_a", "t1_b", "t1_c", "t1_d", "t1_e", "t1_f"]);
+
+        assert_eq!(forest.find("t1_c").map(|c| c.body()), Some("comment c"));
+        assert!(forest.find("t1_x").is_none());
+        assert_eq!(forest.depth_of("t1_c"), Some(2));
+        assert_eq!(forest.depth_of("t1_e"), Some(0));
+
+        let c = forest.find("t1_c").unwrap();
+        assert_eq!(forest.parent_of(c).map(|p| p.name()), Some("t1_b"));
+        let a = forest.find("t1_a").unwrap();
+        assert!(forest.parent_of(a).is_none());
+    }
+}
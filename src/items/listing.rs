@@ -0,0 +1,93 @@
+use crate::reddit::Reddit;
+
+use crate::endpoints::Endpoint;
+use crate::items::AbstractedApi;
+use crate::models::{InvalidChild, ListingChild, RedditResponseGeneric, TaggedListing};
+
+use serde_json::Value;
+use std::io;
+
+/// One page of a listing, e.g. a moderation queue.
+pub struct Listing<'r, T: AbstractedApi<'r>> {
+    reddit: &'r Reddit,
+    endpoint: Endpoint,
+    items: Vec<T::AbstractedType>,
+    invalid_children: Vec<InvalidChild>,
+    before: Option<String>,
+    after: Option<String>,
+}
+
+impl<'r, T> Listing<'r, T>
+where
+    T: AbstractedApi<'r>,
+    T::ApiType: ListingChild,
+{
+    pub(crate) async fn new_listing(reddit: &'r Reddit, endpoint: Endpoint) -> io::Result<Listing<'r, T>> {
+        Self::fetch(reddit, endpoint, None, None).await
+    }
+
+    async fn fetch(
+        reddit: &'r Reddit,
+        endpoint: Endpoint,
+        before: Option<&str>,
+        after: Option<&str>,
+    ) -> io::Result<Listing<'r, T>> {
+        let mut ep = endpoint.clone();
+        if let Some(before) = before {
+            ep = ep.add_query_pairs(&[("before", before)]);
+        }
+        if let Some(after) = after {
+            ep = ep.add_query_pairs(&[("after", after)]);
+        }
+
+        let listing = reddit
+            .api
+            .get_api::<RedditResponseGeneric<TaggedListing<Value>>>(ep.to_url())
+            .await?
+            .data;
+
+        let (children, mut invalid_children) = T::ApiType::from_children(listing.children);
+        invalid_children.extend(listing.invalid_children);
+        Ok(Listing {
+            reddit,
+            endpoint,
+            items: T::list_of(reddit, &children),
+            invalid_children,
+            before: listing.before,
+            after: listing.after,
+        })
+    }
+
+    /// Items on the current page
+    pub fn items(&self) -> &Vec<T::AbstractedType> {
+        &self.items
+    }
+
+    pub fn into_items(self) -> Vec<T::AbstractedType> {
+        self.items
+    }
+
+    /// Children on the current page that could not be parsed,
+    /// they are not included in [Listing::items].
+    pub fn invalid_children(&self) -> &[InvalidChild] {
+        &self.invalid_children
+    }
+
+    /// Next page of items
+    pub async fn next(&self) -> io::Result<Option<Listing<'r, T>>> {
+        Ok(if let Some(next) = &self.after {
+            Some(Self::fetch(self.reddit, self.endpoint.clone(), None, Some(next)).await?)
+        } else {
+            None
+        })
+    }
+
+    /// Previous page of items
+    pub async fn prev(&self) -> io::Result<Option<Listing<'r, T>>> {
+        Ok(if let Some(prev) = &self.before {
+            Some(Self::fetch(self.reddit, self.endpoint.clone(), Some(prev), None).await?)
+        } else {
+            None
+        })
+    }
+}
@@ -0,0 +1,168 @@
+//! Files that can be uploaded to reddit for image, video and gallery posts.
+use std::io;
+use std::path::Path;
+
+/// A file to upload.
+#[derive(Clone, Debug)]
+pub struct MediaFile {
+    pub file_name: String,
+    pub mime_type: String,
+    pub data: Vec<u8>,
+}
+
+/// Guesses the mime type of the media types reddit accepts.
+fn mime_type_of(file_name: &str) -> Option<&'static str> {
+    let ext = Path::new(file_name)
+        .extension()?
+        .to_str()?
+        .to_ascii_lowercase();
+    Some(match ext.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "mp4" => "video/mp4",
+        "mov" => "video/quicktime",
+        _ => return None,
+    })
+}
+
+impl MediaFile {
+    pub fn new(file_name: &str, mime_type: &str, data: Vec<u8>) -> Self {
+        Self {
+            file_name: file_name.to_owned(),
+            mime_type: mime_type.to_owned(),
+            data,
+        }
+    }
+
+    /// Reads a file from disk, the mime type is taken from its extension.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref();
+        let file_name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Invalid file name"))?;
+        let mime_type = mime_type_of(file_name).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Unsupported media type: {}", file_name),
+            )
+        })?;
+
+        Ok(Self::new(file_name, mime_type, std::fs::read(path)?))
+    }
+}
+
+/// An image in a gallery post.
+#[derive(Clone, Debug)]
+pub struct GalleryItem {
+    pub media: MediaFile,
+    pub caption: Option<String>,
+    pub outbound_url: Option<String>,
+}
+
+impl GalleryItem {
+    pub fn new(media: MediaFile) -> Self {
+        Self {
+            media,
+            caption: None,
+            outbound_url: None,
+        }
+    }
+
+    pub fn caption(mut self, caption: &str) -> Self {
+        self.caption = Some(caption.to_owned());
+        self
+    }
+
+    /// Link shown under the image.
+    pub fn outbound_url(mut self, outbound_url: &str) -> Self {
+        self.outbound_url = Some(outbound_url.to_owned());
+        self
+    }
+}
+
+/// A file that has been uploaded to reddit.
+#[derive(Clone, Debug)]
+pub struct UploadedMedia {
+    pub asset_id: String,
+    /// The url to submit image and video posts with.
+    pub url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::MediaAssetLease;
+    use crate::reddit::Reddit;
+    use crate::test_server::serve_static;
+
+    use serde_json::json;
+
+    fn lease(action: &str) -> MediaAssetLease {
+        serde_json::from_value(json!({
+            "args": {
+                "action": action,
+                "fields": [
+                    {"name": "acl", "value": "public-read"},
+                    {"name": "key", "value": "rte_images/abc123.png"},
+                    {"name": "Content-Type", "value": "image/png"},
+                ]
+            },
+            "asset": {"asset_id": "abc123", "processing_state": "incomplete"}
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn upload_to_lease_sends_fields_and_file() {
+        let (url, request) = serve_static("201 Created", "");
+        let reddit = Reddit::new().unwrap();
+        let file = MediaFile::new("cat.png", "image/png", b"not really a png".to_vec());
+
+        let uploaded = reddit.upload_to_lease(&lease(&url), &file).await.unwrap();
+        assert_eq!(uploaded.asset_id, "abc123");
+        assert_eq!(uploaded.url, format!("{}/rte_images/abc123.png", url));
+
+        let request = request.recv().unwrap();
+        assert!(request.starts_with("POST / HTTP/1.1"));
+        assert!(request.contains("multipart/form-data; boundary="));
+        assert!(!request.to_ascii_lowercase().contains("authorization:"));
+
+        for (name, value) in &[
+            ("acl", "public-read"),
+            ("key", "rte_images/abc123.png"),
+            ("Content-Type", "image/png"),
+        ] {
+            let field = format!(
+                "Content-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+                name, value
+            );
+            assert!(request.contains(&field), "missing field {}", name);
+        }
+
+        let file_part = "Content-Disposition: form-data; name=\"file\"; filename=\"cat.png\"\r\n\
+                         Content-Type: image/png\r\n\r\nnot really a png\r\n";
+        assert!(request.contains(file_part), "missing file part");
+
+        // The file has to be the last field for S3 to accept it.
+        let key_at = request.find("name=\"key\"").unwrap();
+        let file_at = request.find("name=\"file\"").unwrap();
+        assert!(key_at < file_at);
+    }
+
+    #[tokio::test]
+    async fn upload_to_lease_fails_on_error_status() {
+        let (url, request) = serve_static("403 Forbidden", "");
+        let reddit = Reddit::new().unwrap();
+        let file = MediaFile::new("cat.png", "image/png", b"png".to_vec());
+
+        let err = reddit
+            .upload_to_lease(&lease(&url), &file)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("403"), "{}", err);
+        assert!(request.recv().unwrap().contains("name=\"file\""));
+    }
+}
@@ -1,16 +1,34 @@
 //! Abstractions over the reddit api so they can be interacted with.
+pub mod forest;
+pub mod listing;
+pub mod media;
+pub mod moderation;
+pub mod moderators;
+pub mod modlog;
+pub mod modmail;
 pub mod post;
+pub mod relationship;
+pub mod requirements;
 pub mod search;
 pub mod submission;
 pub mod subreddit;
+pub mod thing;
 pub mod user;
 
 use crate::reddit::Reddit;
 use serde::de::DeserializeOwned;
 
+pub use forest::{BreadthFirst, CommentForest, DepthFirst};
+pub use listing::Listing;
+pub use media::{GalleryItem, MediaFile, UploadedMedia};
+pub use moderation::{CommentModeration, PostModeration};
+pub use modlog::{ModLogEntry, ModLogOptions};
+pub use modmail::{Modmail, ModmailConversation, ModmailOptions};
 pub use post::Post;
-pub use submission::{Comment, Submission};
-pub use subreddit::{Subreddit, SubredditLink};
+pub use relationship::{BanOptions, RelatedUser, Relationships};
+pub use submission::{Comment, Submission, SubmissionOptions};
+pub use subreddit::{SubmitOptions, SubmittedPost, Subreddit, SubredditLink, SubredditSubmission};
+pub use thing::{InfoResults, Thing};
 pub use user::{RedditUser, RedditUserLink};
 
 // Represents something that is an abstraction over
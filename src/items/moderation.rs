@@ -0,0 +1,270 @@
+//! Moderator actions on posts and comments.
+//! Get these with `post.moderation()` or `comment.moderation()`.
+use crate::endpoints::{self, CommentSort};
+use crate::items::{post::Post, submission::Comment};
+use crate::models::{
+    DistinguishHow, RemovalContext, RemovalMessageType, RemovalReason, SendOriginalContent,
+    SendSticky, SendSuggestedSort,
+};
+
+use std::io;
+
+/// Moderator actions for a [Post].
+pub struct PostModeration<'a, 'r> {
+    post: &'a Post<'r>,
+}
+
+impl<'a, 'r> PostModeration<'a, 'r> {
+    pub(crate) fn new(post: &'a Post<'r>) -> Self {
+        Self { post }
+    }
+
+    fn name(&self) -> &str {
+        self.post.name()
+    }
+
+    pub async fn approve(&self) -> io::Result<()> {
+        self.post.reddit.thing_action(endpoints::APPROVE, self.name()).await
+    }
+
+    pub async fn remove(&self) -> io::Result<()> {
+        self.post.reddit.remove(self.name(), false).await
+    }
+
+    /// Removes the post and trains the spam filter with it.
+    pub async fn remove_spam(&self) -> io::Result<()> {
+        self.post.reddit.remove(self.name(), true).await
+    }
+
+    /// Attaches a removal reason and/or a note for other moderators
+    /// to the post once it has been removed.
+    pub async fn add_removal_reason(&self, reason_id: Option<&str>, mod_note: Option<&str>) -> io::Result<()> {
+        self.post.reddit.apply_removal_reason(self.name(), reason_id, mod_note).await
+    }
+
+    /// Sends the author a removal message. `title` is the subject of private messages.
+    pub async fn send_removal_message(&self, message: &str, title: &str, message_type: RemovalMessageType) -> io::Result<()> {
+        self.post.reddit.send_removal_message(self.name(), message, title, message_type).await
+    }
+
+    /// The values substituted into removal messages for this post.
+    pub fn removal_context(&self) -> RemovalContext {
+        let info = self.post.info();
+        RemovalContext {
+            author: info.author.clone(),
+            subreddit: info.subreddit.clone(),
+            title: info.title.clone(),
+            kind: "submission".to_owned(),
+            url: info
+                .permalink
+                .as_ref()
+                .map(|p| format!("https://www.reddit.com{}", p))
+                .unwrap_or_else(|| info.url.clone()),
+        }
+    }
+
+    /// Removes the post with `reason`, and if `message_type` is set
+    /// sends the author the reasons message with its placeholders filled in.
+    pub async fn remove_with_reason(&self, reason: &RemovalReason, message_type: Option<RemovalMessageType>) -> io::Result<()> {
+        self.remove().await?;
+        self.add_removal_reason(Some(&reason.id), None).await?;
+        if let Some(message_type) = message_type {
+            let message = reason.render(&self.removal_context());
+            self.send_removal_message(&message, &reason.title, message_type).await?;
+        }
+        Ok(())
+    }
+
+    /// Stops new comments being made on the post.
+    pub async fn lock(&self) -> io::Result<()> {
+        self.post.reddit.thing_action(endpoints::LOCK, self.name()).await
+    }
+
+    pub async fn unlock(&self) -> io::Result<()> {
+        self.post.reddit.thing_action(endpoints::UNLOCK, self.name()).await
+    }
+
+    pub async fn mark_nsfw(&self) -> io::Result<()> {
+        self.post.reddit.thing_action(endpoints::MARKNSFW, self.name()).await
+    }
+
+    pub async fn unmark_nsfw(&self) -> io::Result<()> {
+        self.post.reddit.thing_action(endpoints::UNMARKNSFW, self.name()).await
+    }
+
+    pub async fn spoiler(&self) -> io::Result<()> {
+        self.post.reddit.thing_action(endpoints::SPOILER, self.name()).await
+    }
+
+    pub async fn unspoiler(&self) -> io::Result<()> {
+        self.post.reddit.thing_action(endpoints::UNSPOILER, self.name()).await
+    }
+
+    /// Only works on posts made by the authenticated moderator.
+    pub async fn distinguish(&self, how: DistinguishHow) -> io::Result<()> {
+        self.post.reddit.distinguish(self.name(), how, None).await
+    }
+
+    pub async fn undistinguish(&self) -> io::Result<()> {
+        self.distinguish(DistinguishHow::None).await
+    }
+
+    /// Stops reports on the post notifying moderators.
+    pub async fn ignore_reports(&self) -> io::Result<()> {
+        self.post.reddit.thing_action(endpoints::IGNORE_REPORTS, self.name()).await
+    }
+
+    pub async fn unignore_reports(&self) -> io::Result<()> {
+        self.post.reddit.thing_action(endpoints::UNIGNORE_REPORTS, self.name()).await
+    }
+
+    /// Contest mode shows comments in a random order with their scores hidden.
+    pub async fn contest_mode(&self, enabled: bool) -> io::Result<()> {
+        let target_url = self.post.reddit.ep(endpoints::CONTEST_MODE)?;
+        self.post.reddit.set_state(target_url, self.name(), enabled).await
+    }
+
+    /// Sets the default comment sort of the post, `None` uses the subreddit default.
+    pub async fn suggested_sort(&self, sort: Option<CommentSort>) -> io::Result<()> {
+        let target_url = self.post.reddit.ep(endpoints::SUGGESTED_SORT)?;
+        self.post.reddit.post_action(target_url, &SendSuggestedSort {
+            id: self.name(),
+            sort: sort.map(|s| s.to_str()).unwrap_or("blank"),
+        }).await
+    }
+
+    /// Marks the post as original content.
+    pub async fn set_original_content(&self, is_oc: bool) -> io::Result<()> {
+        let target_url = self.post.reddit.ep(endpoints::SET_ORIGINAL_CONTENT)?;
+        self.post.reddit.post_action(target_url, &SendOriginalContent {
+            id: self.name(),
+            fullname: self.name(),
+            should_set_oc: is_oc,
+        }).await
+    }
+
+    /// Stickies the post to the top of the subreddit.
+    /// `slot` picks which sticky to replace (1-4), otherwise the bottom one is used.
+    pub async fn sticky(&self, slot: Option<u8>) -> io::Result<()> {
+        let target_url = self.post.reddit.ep(endpoints::STICKY_SUBMISSION)?;
+        self.post.reddit.post_action(target_url, &SendSticky {
+            id: self.name(),
+            state: true,
+            num: slot,
+        }).await
+    }
+
+    pub async fn unsticky(&self) -> io::Result<()> {
+        let target_url = self.post.reddit.ep(endpoints::STICKY_SUBMISSION)?;
+        self.post.reddit.post_action(target_url, &SendSticky {
+            id: self.name(),
+            state: false,
+            num: None,
+        }).await
+    }
+}
+
+/// Moderator actions for a [Comment].
+pub struct CommentModeration<'a, 'r> {
+    comment: &'a Comment<'r>,
+}
+
+impl<'a, 'r> CommentModeration<'a, 'r> {
+    pub(crate) fn new(comment: &'a Comment<'r>) -> Self {
+        Self { comment }
+    }
+
+    fn name(&self) -> &str {
+        self.comment.name()
+    }
+
+    pub async fn approve(&self) -> io::Result<()> {
+        self.comment.reddit.thing_action(endpoints::APPROVE, self.name()).await
+    }
+
+    pub async fn remove(&self) -> io::Result<()> {
+        self.comment.reddit.remove(self.name(), false).await
+    }
+
+    /// Removes the comment and trains the spam filter with it.
+    pub async fn remove_spam(&self) -> io::Result<()> {
+        self.comment.reddit.remove(self.name(), true).await
+    }
+
+    /// Attaches a removal reason and/or a note for other moderators
+    /// to the comment once it has been removed.
+    pub async fn add_removal_reason(&self, reason_id: Option<&str>, mod_note: Option<&str>) -> io::Result<()> {
+        self.comment.reddit.apply_removal_reason(self.name(), reason_id, mod_note).await
+    }
+
+    /// Sends the author a removal message. `title` is the subject of private messages.
+    pub async fn send_removal_message(&self, message: &str, title: &str, message_type: RemovalMessageType) -> io::Result<()> {
+        self.comment.reddit.send_removal_message(self.name(), message, title, message_type).await
+    }
+
+    /// The values substituted into removal messages for this comment.
+    pub fn removal_context(&self) -> RemovalContext {
+        let info = self.comment.info();
+        let url = match info.extra.get("permalink").and_then(|p| p.as_str()) {
+            Some(permalink) => format!("https://www.reddit.com{}", permalink),
+            None => format!(
+                "https://www.reddit.com/r/{}/comments/{}/_/{}/",
+                info.subreddit,
+                info.link_id.trim_start_matches("t3_"),
+                self.name().trim_start_matches("t1_"),
+            ),
+        };
+        RemovalContext {
+            author: info.author.clone(),
+            subreddit: info.subreddit.clone(),
+            title: info.link_title.clone().unwrap_or_default(),
+            kind: "comment".to_owned(),
+            url,
+        }
+    }
+
+    /// Removes the comment with `reason`, and if `message_type` is set
+    /// sends the author the reasons message with its placeholders filled in.
+    pub async fn remove_with_reason(&self, reason: &RemovalReason, message_type: Option<RemovalMessageType>) -> io::Result<()> {
+        self.remove().await?;
+        self.add_removal_reason(Some(&reason.id), None).await?;
+        if let Some(message_type) = message_type {
+            let message = reason.render(&self.removal_context());
+            self.send_removal_message(&message, &reason.title, message_type).await?;
+        }
+        Ok(())
+    }
+
+    /// Stops replies being made to the comment.
+    pub async fn lock(&self) -> io::Result<()> {
+        self.comment.reddit.thing_action(endpoints::LOCK, self.name()).await
+    }
+
+    pub async fn unlock(&self) -> io::Result<()> {
+        self.comment.reddit.thing_action(endpoints::UNLOCK, self.name()).await
+    }
+
+    /// Only works on comments made by the authenticated moderator.
+    /// `sticky` pins a top level comment to the top of the thread.
+    pub async fn distinguish(&self, how: DistinguishHow, sticky: bool) -> io::Result<()> {
+        self.comment.reddit.distinguish(self.name(), how, Some(sticky)).await
+    }
+
+    pub async fn undistinguish(&self) -> io::Result<()> {
+        self.comment.reddit.distinguish(self.name(), DistinguishHow::None, None).await
+    }
+
+    /// Stops reports on the comment notifying moderators.
+    pub async fn ignore_reports(&self) -> io::Result<()> {
+        self.comment.reddit.thing_action(endpoints::IGNORE_REPORTS, self.name()).await
+    }
+
+    pub async fn unignore_reports(&self) -> io::Result<()> {
+        self.comment.reddit.thing_action(endpoints::UNIGNORE_REPORTS, self.name()).await
+    }
+
+    /// Shows a comment that was collapsed by crowd control.
+    pub async fn show(&self) -> io::Result<()> {
+        self.comment.reddit.thing_action(endpoints::SHOW_COMMENT, self.name()).await
+    }
+}
@@ -0,0 +1,115 @@
+//! Managing a subreddits moderators.
+//!
+//! ```no_run
+//! # use snoo::{models::{ModPermission, ModPermissions}, Reddit};
+//! # async fn example(r: Reddit) -> std::io::Result<()> {
+//! let sub = r.subreddit("rust");
+//! let perms = ModPermissions::none().with(ModPermission::Posts);
+//! sub.invite_moderator("a_user", &perms).await?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::endpoints;
+use crate::items::subreddit::SubredditLink;
+use crate::models::{
+    ModPermissions, ModeratorInfo, RedditResponseGeneric, RelationshipType, SendFriend,
+    SendSetPermissions, SendThingId, SubredditInfo, TaggedListing,
+};
+
+use std::io;
+
+impl<'r> SubredditLink<'r> {
+    /// The moderators of the subreddit and their permissions,
+    /// in the order they are shown on the subreddit.
+    pub async fn moderators(&self) -> io::Result<Vec<ModeratorInfo>> {
+        let ep = self.reddit.ep(endpoints::LIST_MODERATOR.subreddit(self.name()))?;
+        let list = self
+            .reddit
+            .api
+            .get_api::<RedditResponseGeneric<TaggedListing<ModeratorInfo>>>(ep.to_url())
+            .await?;
+        Ok(list.data.children)
+    }
+
+    /// Invites `user` to be a moderator with `permissions`.
+    pub async fn invite_moderator(&self, user: &str, permissions: &ModPermissions) -> io::Result<()> {
+        let permissions = permissions.to_param();
+        let mut send = SendFriend::new(user, RelationshipType::ModeratorInvite);
+        send.permissions = Some(&permissions);
+        self.relationships().add(&send).await
+    }
+
+    /// Withdraws a moderator invite that has not been accepted.
+    pub async fn revoke_moderator_invite(&self, user: &str) -> io::Result<()> {
+        self.relationships()
+            .remove(user, RelationshipType::ModeratorInvite)
+            .await
+    }
+
+    pub async fn remove_moderator(&self, user: &str) -> io::Result<()> {
+        self.relationships()
+            .remove(user, RelationshipType::Moderator)
+            .await
+    }
+
+    /// Changes the permissions of a moderator.
+    pub async fn set_moderator_permissions(&self, user: &str, permissions: &ModPermissions) -> io::Result<()> {
+        self.set_permissions(user, RelationshipType::Moderator, permissions)
+            .await
+    }
+
+    /// Changes the permissions `user` will get when they accept their invite.
+    pub async fn set_invite_permissions(&self, user: &str, permissions: &ModPermissions) -> io::Result<()> {
+        self.set_permissions(user, RelationshipType::ModeratorInvite, permissions)
+            .await
+    }
+
+    async fn set_permissions(
+        &self,
+        user: &str,
+        relationship: RelationshipType,
+        permissions: &ModPermissions,
+    ) -> io::Result<()> {
+        let ep = self.reddit.ep(endpoints::SETPERMISSIONS.subreddit(self.name()))?;
+        self.reddit
+            .post_action(ep, &SendSetPermissions {
+                name: user,
+                relationship: relationship.to_str(),
+                permissions: &permissions.to_param(),
+            })
+            .await
+    }
+
+    /// Accepts an invite to moderate the subreddit.
+    pub async fn accept_moderator_invite(&self) -> io::Result<()> {
+        let ep = self.reddit.ep(endpoints::ACCEPT_MOD_INVITE.subreddit(self.name()))?;
+        self.reddit.post_action(ep, &()).await
+    }
+
+    /// Declines an invite to moderate the subreddit
+    /// by removing the authenticated user from the invite list.
+    pub async fn decline_moderator_invite(&self) -> io::Result<()> {
+        let me = self.reddit.api.me().await?;
+        self.revoke_moderator_invite(&me.name).await
+    }
+
+    /// Stops moderating the subreddit.
+    pub async fn leave_moderator(&self) -> io::Result<()> {
+        self.leave(endpoints::LEAVEMODERATOR).await
+    }
+
+    /// Stops being an approved user of the subreddit.
+    pub async fn leave_contributor(&self) -> io::Result<()> {
+        self.leave(endpoints::LEAVECONTRIBUTOR).await
+    }
+
+    async fn leave(&self, builder: endpoints::EndpointBuilder) -> io::Result<()> {
+        let about = self.reddit.ep(endpoints::SUBREDDIT_ABOUT.subreddit(self.name()))?;
+        let info = self.reddit.get_data::<SubredditInfo>(about).await?;
+
+        let ep = self.reddit.ep(builder)?;
+        self.reddit
+            .post_action(ep, &SendThingId { id: &info.data.name })
+            .await
+    }
+}
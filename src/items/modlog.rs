@@ -0,0 +1,151 @@
+//! A subreddits moderation log.
+//!
+//! ```no_run
+//! # use snoo::{models::ModActionType, ModLogOptions, Reddit};
+//! # async fn example(r: Reddit) -> std::io::Result<()> {
+//! let options = ModLogOptions::new()
+//!     .moderator("a_moderator")
+//!     .action(ModActionType::RemoveLink);
+//! let log = r.subreddit("rust").mod_log(&options).await?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::endpoints::Endpoint;
+use crate::items::{thing::Thing, AbstractedApi};
+use crate::models::{Fullname, ModAction, ModActionType, ThingKind};
+use crate::reddit::Reddit;
+
+use chrono::{DateTime, Utc};
+use std::io;
+
+/// Filters for the mod log.
+#[derive(Default, Clone, Debug)]
+pub struct ModLogOptions {
+    moderators: Vec<String>,
+    action: Option<ModActionType>,
+}
+
+impl ModLogOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only show actions by `moderator`.
+    /// Can be used more than once to show actions by several moderators.
+    pub fn moderator(mut self, moderator: &str) -> Self {
+        self.moderators.push(moderator.to_owned());
+        self
+    }
+
+    /// Only show actions of one type.
+    pub fn action(mut self, action: ModActionType) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    pub(crate) fn apply(&self, ep: Endpoint) -> Endpoint {
+        let moderators = self.moderators.join(",");
+
+        let mut pairs = Vec::new();
+        if !moderators.is_empty() {
+            pairs.push(("mod", moderators.as_str()));
+        }
+        if let Some(action) = &self.action {
+            pairs.push(("type", action.to_str()));
+        }
+        ep.add_query_pairs(&pairs)
+    }
+}
+
+/// An entry in the mod log.
+pub struct ModLogEntry<'r> {
+    reddit: &'r Reddit,
+    info: ModAction,
+}
+
+impl<'r> ModLogEntry<'r> {
+    pub fn info(&self) -> &ModAction {
+        &self.info
+    }
+
+    pub fn action(&self) -> &ModActionType {
+        &self.info.action
+    }
+
+    /// Username of the moderator that did the action.
+    pub fn moderator(&self) -> &str {
+        &self.info.moderator
+    }
+
+    pub fn created_utc(&self) -> DateTime<Utc> {
+        self.info.created_utc
+    }
+
+    /// Loads the post or comment the action was done to.
+    /// None if the target was a user or setting, or reddit did not return it.
+    pub async fn target(&self) -> io::Result<Option<Thing<'r>>> {
+        let name = match &self.info.target_fullname {
+            Some(name) => Fullname::parse(name)?,
+            None => return Ok(None),
+        };
+        match name.kind() {
+            ThingKind::Link | ThingKind::Comment => {}
+            _ => return Ok(None),
+        }
+
+        let results = self.reddit.info(&[name]).await?;
+        Ok(results.found.into_iter().next())
+    }
+}
+
+impl<'r> AbstractedApi<'r> for ModLogEntry<'r> {
+    type ApiType = ModAction;
+    type AbstractedType = ModLogEntry<'r>;
+
+    fn from_parent(reddit: &'r Reddit, info: Self::ApiType) -> ModLogEntry<'r> {
+        ModLogEntry { reddit, info }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints;
+
+    fn query(options: &ModLogOptions) -> Vec<(String, String)> {
+        let ep = endpoints::ABOUT_LOG.subreddit("rust").oauth_ep().unwrap();
+        options
+            .apply(ep)
+            .to_url()
+            .query_pairs()
+            .into_owned()
+            .collect()
+    }
+
+    #[test]
+    fn default_options_add_nothing() {
+        assert!(query(&ModLogOptions::new()).is_empty());
+    }
+
+    #[test]
+    fn options_query() {
+        let options = ModLogOptions::new()
+            .moderator("first_mod")
+            .moderator("second_mod")
+            .action(ModActionType::RemoveComment);
+        assert_eq!(
+            query(&options),
+            [
+                ("mod".to_owned(), "first_mod,second_mod".to_owned()),
+                ("type".to_owned(), "removecomment".to_owned()),
+            ]
+        );
+
+        let options =
+            ModLogOptions::new().action(ModActionType::Other("some_new_action".to_owned()));
+        assert_eq!(
+            query(&options),
+            [("type".to_owned(), "some_new_action".to_owned())]
+        );
+    }
+}
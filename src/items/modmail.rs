@@ -0,0 +1,335 @@
+//! New modmail, get this with `reddit.modmail()`
+//!
+//! ```no_run
+//! # use snoo::{models::ModmailState, ModmailOptions, Reddit};
+//! # async fn example(r: Reddit) -> std::io::Result<()> {
+//! let options = ModmailOptions::new().state(ModmailState::New);
+//! for conversation in r.modmail().conversations(&options).await? {
+//!     conversation.reply("Thanks, we will look into it.", false).await?;
+//!     conversation.archive().await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use crate::endpoints::{self, Endpoint};
+use crate::feed::ModmailStream;
+use crate::models::{
+    ModmailBulkRead, ModmailConversationInfo, ModmailConversationResponse, ModmailConversations,
+    ModmailMessage, ModmailMuteDuration, ModmailSort, ModmailState, ModmailUnreadCount,
+    SendModmailBulkRead, SendModmailCreate, SendModmailMute, SendModmailRead, SendModmailReply,
+};
+use crate::reddit::Reddit;
+
+use reqwest::Method;
+use std::io;
+
+/// Which conversations to list.
+#[derive(Default, Clone, Debug)]
+pub struct ModmailOptions {
+    subreddits: Vec<String>,
+    state: Option<ModmailState>,
+    sort: Option<ModmailSort>,
+    limit: Option<u32>,
+    after: Option<String>,
+}
+
+impl ModmailOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only list conversations in `subreddit`.
+    /// Can be used more than once, all moderated subreddits are listed if not set.
+    pub fn subreddit(mut self, subreddit: &str) -> Self {
+        self.subreddits.push(subreddit.to_owned());
+        self
+    }
+
+    pub fn state(mut self, state: ModmailState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    pub fn sort(mut self, sort: ModmailSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Max number of conversations to return, up to 100.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Only list conversations after the one with the id `conversation_id`.
+    pub fn after(mut self, conversation_id: &str) -> Self {
+        self.after = Some(conversation_id.to_owned());
+        self
+    }
+
+    pub(crate) fn apply(&self, ep: Endpoint) -> Endpoint {
+        let subreddits = self.subreddits.join(",");
+        let limit = self.limit.map(|l| l.to_string());
+
+        let mut pairs = Vec::new();
+        if !subreddits.is_empty() {
+            pairs.push(("entity", subreddits.as_str()));
+        }
+        if let Some(state) = self.state {
+            pairs.push(("state", state.to_str()));
+        }
+        if let Some(sort) = self.sort {
+            pairs.push(("sort", sort.to_str()));
+        }
+        if let Some(limit) = &limit {
+            pairs.push(("limit", limit.as_str()));
+        }
+        if let Some(after) = &self.after {
+            pairs.push(("after", after.as_str()));
+        }
+        ep.add_query_pairs(&pairs)
+    }
+}
+
+/// Modmail of every subreddit the authenticated user moderates.
+pub struct Modmail<'r> {
+    reddit: &'r Reddit,
+}
+
+impl<'r> Modmail<'r> {
+    pub(crate) fn new(reddit: &'r Reddit) -> Self {
+        Self { reddit }
+    }
+
+    /// Lists conversations. Only the latest message of each conversation is loaded,
+    /// use [ModmailConversation::load] to get the rest.
+    pub async fn conversations(&self, options: &ModmailOptions) -> io::Result<Vec<ModmailConversation<'r>>> {
+        let ep = options.apply(self.reddit.ep(endpoints::MODMAIL_CONVERSATIONS)?);
+        let resp = self
+            .reddit
+            .api
+            .get_api::<ModmailConversations>(ep.to_url())
+            .await?;
+
+        Ok(resp
+            .into_ordered()
+            .into_iter()
+            .map(|(info, messages)| ModmailConversation::new(self.reddit, info, messages))
+            .collect())
+    }
+
+    /// Loads a conversation with all of its messages.
+    pub async fn conversation(&self, id: &str, mark_read: bool) -> io::Result<ModmailConversation<'r>> {
+        let ep = self
+            .reddit
+            .ep(endpoints::MODMAIL_CONVERSATION.id(id))?
+            .add_query_pairs(&[("markRead", if mark_read { "true" } else { "false" })]);
+        let resp = self
+            .reddit
+            .api
+            .get_api::<ModmailConversationResponse>(ep.to_url())
+            .await?;
+        Ok(ModmailConversation::from_response(self.reddit, resp))
+    }
+
+    /// Starts a new conversation from `subreddit`.
+    /// If `to` is None the conversation is only between moderators.
+    pub async fn create(
+        &self,
+        subreddit: &str,
+        to: Option<&str>,
+        subject: &str,
+        body: &str,
+        hide_author: bool,
+    ) -> io::Result<ModmailConversation<'r>> {
+        let ep = self.reddit.ep(endpoints::MODMAIL_CONVERSATIONS)?;
+        let resp = self
+            .reddit
+            .api
+            .post_form_api::<ModmailConversationResponse, _>(ep.to_url(), &SendModmailCreate {
+                body,
+                is_author_hidden: hide_author,
+                sr_name: subreddit,
+                subject,
+                to,
+            })
+            .await?;
+        Ok(ModmailConversation::from_response(self.reddit, resp))
+    }
+
+    /// Stream of new conversations and messages.
+    pub fn stream(&self) -> ModmailStream {
+        ModmailStream::new(self.reddit.clone())
+    }
+
+    /// Number of unread conversations in each state.
+    pub async fn unread_count(&self) -> io::Result<ModmailUnreadCount> {
+        let ep = self.reddit.ep(endpoints::MODMAIL_UNREAD_COUNT)?;
+        self.reddit.api.get_api(ep.to_url()).await
+    }
+
+    pub async fn mark_read(&self, conversation_ids: &[&str]) -> io::Result<()> {
+        self.mark(endpoints::MODMAIL_READ, conversation_ids).await
+    }
+
+    pub async fn mark_unread(&self, conversation_ids: &[&str]) -> io::Result<()> {
+        self.mark(endpoints::MODMAIL_UNREAD, conversation_ids).await
+    }
+
+    async fn mark(&self, builder: endpoints::EndpointBuilder, conversation_ids: &[&str]) -> io::Result<()> {
+        let ep = self.reddit.ep(builder)?;
+        self.reddit
+            .api
+            .form_action(Method::POST, ep.to_url(), &SendModmailRead {
+                conversation_ids: &conversation_ids.join(","),
+            })
+            .await
+    }
+
+    /// Marks every conversation in `state` as read.
+    /// Only conversations in `subreddits` are marked if any are given.
+    /// Returns the ids of the conversations that were marked.
+    pub async fn bulk_read(&self, subreddits: &[&str], state: ModmailState) -> io::Result<Vec<String>> {
+        let entity = subreddits.join(",");
+        let ep = self.reddit.ep(endpoints::MODMAIL_BULK_READ)?;
+        let resp = self
+            .reddit
+            .api
+            .post_form_api::<ModmailBulkRead, _>(ep.to_url(), &SendModmailBulkRead {
+                entity: if entity.is_empty() { None } else { Some(&entity) },
+                state: state.to_str(),
+            })
+            .await?;
+        Ok(resp.conversation_ids)
+    }
+}
+
+/// A modmail conversation and the messages that have been loaded.
+pub struct ModmailConversation<'r> {
+    reddit: &'r Reddit,
+    info: ModmailConversationInfo,
+    messages: Vec<ModmailMessage>,
+}
+
+impl<'r> ModmailConversation<'r> {
+    fn new(reddit: &'r Reddit, info: ModmailConversationInfo, messages: Vec<ModmailMessage>) -> Self {
+        Self {
+            reddit,
+            info,
+            messages,
+        }
+    }
+
+    fn from_response(reddit: &'r Reddit, resp: ModmailConversationResponse) -> Self {
+        let (info, messages) = resp.into_parts();
+        Self::new(reddit, info, messages)
+    }
+
+    pub fn info(&self) -> &ModmailConversationInfo {
+        &self.info
+    }
+
+    pub fn id(&self) -> &str {
+        &self.info.id
+    }
+
+    pub fn subject(&self) -> &str {
+        &self.info.subject
+    }
+
+    /// Name of the subreddit the conversation is in.
+    pub fn subreddit(&self) -> &str {
+        &self.info.owner.display_name
+    }
+
+    /// The loaded messages, oldest first.
+    pub fn messages(&self) -> &[ModmailMessage] {
+        &self.messages
+    }
+
+    /// Reloads the conversation with all of its messages.
+    pub async fn load(&mut self, mark_read: bool) -> io::Result<()> {
+        let loaded = Modmail::new(self.reddit)
+            .conversation(&self.info.id, mark_read)
+            .await?;
+        self.info = loaded.info;
+        self.messages = loaded.messages;
+        Ok(())
+    }
+
+    /// Replies to the conversation as the authenticated moderator.
+    pub async fn reply(&self, body: &str, hide_author: bool) -> io::Result<ModmailMessage> {
+        self.send_reply(body, hide_author, false).await
+    }
+
+    /// Adds a private note only other moderators can see.
+    pub async fn reply_internal(&self, body: &str) -> io::Result<ModmailMessage> {
+        self.send_reply(body, false, true).await
+    }
+
+    async fn send_reply(&self, body: &str, hide_author: bool, internal: bool) -> io::Result<ModmailMessage> {
+        let ep = self.ep(endpoints::MODMAIL_CONVERSATION)?;
+        let resp = self
+            .reddit
+            .api
+            .post_form_api::<ModmailConversationResponse, _>(ep.to_url(), &SendModmailReply {
+                body,
+                is_author_hidden: hide_author,
+                is_internal: internal,
+            })
+            .await?;
+
+        let (_, mut messages) = resp.into_parts();
+        messages
+            .pop()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "No message in response."))
+    }
+
+    pub async fn archive(&self) -> io::Result<()> {
+        self.action(Method::POST, endpoints::MODMAIL_ARCHIVE).await
+    }
+
+    pub async fn unarchive(&self) -> io::Result<()> {
+        self.action(Method::POST, endpoints::MODMAIL_UNARCHIVE).await
+    }
+
+    pub async fn highlight(&self) -> io::Result<()> {
+        self.action(Method::POST, endpoints::MODMAIL_HIGHLIGHT).await
+    }
+
+    pub async fn unhighlight(&self) -> io::Result<()> {
+        self.action(Method::DELETE, endpoints::MODMAIL_HIGHLIGHT).await
+    }
+
+    /// Stops the user in the conversation from sending modmail to the subreddit.
+    pub async fn mute(&self, duration: ModmailMuteDuration) -> io::Result<()> {
+        let ep = self.ep(endpoints::MODMAIL_MUTE)?;
+        self.reddit
+            .api
+            .form_action(Method::POST, ep.to_url(), &SendModmailMute {
+                num_hours: duration.hours(),
+            })
+            .await
+    }
+
+    pub async fn unmute(&self) -> io::Result<()> {
+        self.action(Method::POST, endpoints::MODMAIL_UNMUTE).await
+    }
+
+    pub async fn mark_read(&self) -> io::Result<()> {
+        Modmail::new(self.reddit).mark_read(&[self.id()]).await
+    }
+
+    pub async fn mark_unread(&self) -> io::Result<()> {
+        Modmail::new(self.reddit).mark_unread(&[self.id()]).await
+    }
+
+    fn ep(&self, builder: endpoints::EndpointBuilder) -> io::Result<Endpoint> {
+        self.reddit.ep(builder.id(&self.info.id))
+    }
+
+    async fn action(&self, method: Method, builder: endpoints::EndpointBuilder) -> io::Result<()> {
+        let ep = self.ep(builder)?;
+        self.reddit.api.empty_action(method, ep.to_url()).await
+    }
+}
@@ -1,21 +1,22 @@
 use crate::items::{
-    submission::Submission, subreddit::SubredditLink, user::RedditUserLink, AbstractedApi,
+    moderation::PostModeration,
+    submission::{Comment, Submission, SubmissionOptions}, subreddit::{SubmittedPost, SubredditLink, SubredditSubmission}, user::RedditUserLink, AbstractedApi,
 };
 use crate::models::{
-    RedditResponseGeneric,
-    ThingsResponse,
+    Fullname,
+    PollData,
     PostInfo,
-    PostEditText,
+    ReportReason,
+    VoteDirection,
     PostSetFlair,
-    SendComment,
-    CommentData
 };
 use crate::reddit::Reddit;
 use crate::endpoints;
+use chrono::{DateTime, Utc};
 use std::io;
 
 pub struct Post<'r> {
-    reddit: &'r Reddit,
+    pub(crate) reddit: &'r Reddit,
     info: PostInfo,
 }
 
@@ -26,12 +27,12 @@ impl<'r> AbstractedApi<'r> for Post<'r> {
     fn from_parent(parent: &'r Reddit, info: Self::ApiType) -> Self {
         Self {
             reddit: parent,
-            info: info,
+            info,
         }
     }
 }
 
-impl Post<'_> {
+impl<'r> Post<'r> {
     /// Returns the underlying [PostInfo] model.
     pub fn info(&self) -> &PostInfo {
         &self.info
@@ -46,32 +47,59 @@ impl Post<'_> {
     }
 
     
+    /// The poll if this is a poll post.
+    pub fn poll(&self) -> Option<&PollData> {
+        self.info.poll_data.as_ref()
+    }
+
     pub fn title(&self) -> &str {
         self.info.title.as_ref()
     }
 
-    pub fn subreddit(&'_ self) -> SubredditLink {
+    /// When the post was submitted.
+    pub fn created(&self) -> DateTime<Utc> {
+        self.info.created
+    }
+
+    /// Same as `created`, read from the `created_utc` field.
+    pub fn created_utc(&self) -> DateTime<Utc> {
+        self.info.created_utc
+    }
+
+    /// When the post was last edited, if it has been.
+    pub fn edited(&self) -> Option<DateTime<Utc>> {
+        self.info.moderate_data.edited.time()
+    }
+
+    pub fn subreddit(&'_ self) -> SubredditLink<'_> {
         self.reddit.subreddit(&self.info.subreddit)
     }
 
-    pub fn author(&self) -> RedditUserLink {
+    pub fn author(&self) -> RedditUserLink<'_> {
         RedditUserLink::new(self.reddit, &self.info.author)
     }
 
+    /// Loads the post's comments.
     pub async fn submission(&'_ self) -> io::Result<Submission<'_>> {
-        self.reddit.submission_from_link(&self.url()).await
+        self.reddit.submission(self.name().trim_start_matches("t3_")).await
+    }
+
+    /// Crossposts this post to `subreddit`.
+    pub async fn crosspost(&self, subreddit: &str, title: &str) -> io::Result<SubmittedPost<'r>> {
+        self.reddit
+            .subreddit(subreddit)
+            .submit(title, SubredditSubmission::Crosspost(self.name()))
+            .await
     }
 
-    pub async fn comment(&self, message: &str) -> io::Result<CommentData> {
-        let target_url = self.reddit.ep(endpoints::COMMENT)?;
-        let mut resp= self.reddit.post_data::<_, ThingsResponse<RedditResponseGeneric<CommentData>>>(target_url, &SendComment{
-            thing_id: self.name(),
-            text: message,
-        }).await?;
+    /// Loads the post's comments using `options`.
+    pub async fn submission_with(&self, options: &SubmissionOptions) -> io::Result<Submission<'_>> {
+        self.reddit.submission_with(self.name().trim_start_matches("t3_"), options).await
+    }
 
-        Ok(
-            resp.things.swap_remove(0).data
-        )
+    /// Comments on the post and returns the new [Comment].
+    pub async fn comment(&self, message: &str) -> io::Result<Comment<'r>> {
+        self.reddit.comment(self.name(), message).await
     }
 
     pub async fn set_flair(&self, flair_text: &str, flair_class: &str) -> io::Result<()> {
@@ -83,16 +111,85 @@ impl Post<'_> {
         }).await
     }
 
-    pub async fn edit_text(&self, new_text: &str) -> io::Result<()> {
-        let target_url = self.reddit.ep(endpoints::EDIT)?;
-        self.reddit.post_data(target_url, &PostEditText {
-            thing_id: self.name(),
-            new_text
-        }).await
+    /// Edits the body of a self post.
+    /// Returns the updated [PostInfo], which also replaces the one in this [Post].
+    pub async fn edit_text(&mut self, new_text: &str) -> io::Result<PostInfo> {
+        let info = self.reddit.edit_text::<PostInfo>(self.name(), new_text).await?;
+        self.info = info.clone();
+        Ok(info)
+    }
+
+    /// Reports the post to the subreddits moderators.
+    /// The available reasons can be found with [SubredditLink::rules].
+    pub async fn report(&self, reason: ReportReason<'_>) -> io::Result<()> {
+        self.reddit.report(self.name(), reason).await
+    }
+
+    /// Deletes the post.
+    pub async fn delete(&self) -> io::Result<()> {
+        self.reddit.delete(self.name()).await
+    }
+
+    /// Votes on the post and updates [VoteData](crate::models::VoteData).
+    /// Fails with [SnooError::Archived](crate::SnooError::Archived) if the post is archived.
+    pub async fn vote(&mut self, direction: VoteDirection) -> io::Result<()> {
+        self.reddit.vote(&self.info.moderate_data, direction).await?;
+        self.info.vote_data.apply_vote(direction);
+        Ok(())
     }
 
+    pub async fn upvote(&mut self) -> io::Result<()> {
+        self.vote(VoteDirection::Up).await
+    }
+
+    pub async fn downvote(&mut self) -> io::Result<()> {
+        self.vote(VoteDirection::Down).await
+    }
+
+    pub async fn clear_vote(&mut self) -> io::Result<()> {
+        self.vote(VoteDirection::Clear).await
+    }
+
+    /// Saves the post.
+    /// `category` can only be used by accounts with reddit premium.
+    pub async fn save(&mut self, category: Option<&str>) -> io::Result<()> {
+        self.reddit.save(self.name(), category).await?;
+        self.info.moderate_data.saved = true;
+        Ok(())
+    }
+
+    pub async fn unsave(&mut self) -> io::Result<()> {
+        self.reddit.unsave(self.name()).await?;
+        self.info.moderate_data.saved = false;
+        Ok(())
+    }
+
+    /// Hides the post from the users listings.
+    /// Use [Reddit::hide] to hide many posts at once.
+    pub async fn hide(&mut self) -> io::Result<()> {
+        self.reddit.hide(&[Fullname::parse(self.name())?]).await?;
+        self.info.is_hidden = true;
+        Ok(())
+    }
+
+    pub async fn unhide(&mut self) -> io::Result<()> {
+        self.reddit.unhide(&[Fullname::parse(self.name())?]).await?;
+        self.info.is_hidden = false;
+        Ok(())
+    }
+
+    /// Moderator actions for the post.
+    pub fn moderation(&self) -> PostModeration<'_, 'r> {
+        PostModeration::new(self)
+    }
+
+    #[deprecated(note = "use `post.moderation().sticky(None)` or `post.moderation().unsticky()`")]
     pub async fn set_sticky(&self, stickied: bool) -> io::Result<()> {
-        let target_url = self.reddit.ep(endpoints::STICKY_SUBMISSION)?;
-        self.reddit.set_state(target_url, self.name(), stickied).await
+        let moderation = self.moderation();
+        if stickied {
+            moderation.sticky(None).await
+        } else {
+            moderation.unsticky().await
+        }
     }
 }
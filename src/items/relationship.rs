@@ -0,0 +1,344 @@
+//! Banning, muting and approving users in a subreddit.
+//! Get these with `subreddit.relationships()`.
+//!
+//! ```no_run
+//! # use snoo::{BanOptions, Reddit};
+//! # async fn example(r: Reddit) -> std::io::Result<()> {
+//! let sub = r.subreddit("rust");
+//! let ban = BanOptions::new()
+//!     .duration(7)
+//!     .reason("Spam")
+//!     .message("Please read the rules.");
+//! sub.relationships().ban("a_user", &ban).await?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::endpoints::{self, EndpointBuilder};
+use crate::items::{listing::Listing, subreddit::SubredditLink, user::RedditUserLink, AbstractedApi};
+use crate::models::{RelationshipInfo, RelationshipType, SendFriend, SendUnfriend, BAN_DURATION_MAX};
+use crate::reddit::Reddit;
+
+use chrono::{DateTime, Utc};
+use std::io;
+
+/// Details of a ban.
+/// Bans are permanent unless a duration is set.
+#[derive(Default, Clone, Debug)]
+pub struct BanOptions {
+    duration: Option<u32>,
+    reason: Option<String>,
+    note: Option<String>,
+    message: Option<String>,
+    context: Option<String>,
+}
+
+impl BanOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Length of the ban in days, 1-999.
+    pub fn duration(mut self, days: u32) -> Self {
+        self.duration = Some(days);
+        self
+    }
+
+    /// Reason for the ban, usually one of the subreddits rules.
+    pub fn reason(mut self, reason: &str) -> Self {
+        self.reason = Some(reason.to_owned());
+        self
+    }
+
+    /// Note for other moderators, not shown to the user.
+    pub fn note(mut self, note: &str) -> Self {
+        self.note = Some(note.to_owned());
+        self
+    }
+
+    /// Message sent to the user.
+    pub fn message(mut self, message: &str) -> Self {
+        self.message = Some(message.to_owned());
+        self
+    }
+
+    /// Fullname of the post or comment the user is banned for.
+    pub fn context(mut self, name: &str) -> Self {
+        self.context = Some(name.to_owned());
+        self
+    }
+
+    pub(crate) fn apply<'a>(&'a self, send: &mut SendFriend<'a>) -> io::Result<()> {
+        if let Some(days) = self.duration {
+            if days == 0 || days > BAN_DURATION_MAX {
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("Ban duration must be between 1 and {} days.", BAN_DURATION_MAX),
+                ))?;
+            }
+        }
+
+        send.duration = self.duration;
+        send.ban_reason = self.reason.as_deref();
+        send.note = self.note.as_deref();
+        send.ban_message = self.message.as_deref();
+        send.ban_context = self.context.as_deref();
+        Ok(())
+    }
+
+    /// Wiki bans only have a duration and a note,
+    /// the reason, message and context are not sent.
+    pub(crate) fn apply_wiki<'a>(&'a self, send: &mut SendFriend<'a>) -> io::Result<()> {
+        self.apply(send)?;
+        send.ban_reason = None;
+        send.ban_message = None;
+        send.ban_context = None;
+        Ok(())
+    }
+}
+
+/// Manages the users related to a subreddit.
+pub struct Relationships<'a, 'r> {
+    link: &'a SubredditLink<'r>,
+}
+
+impl<'a, 'r> Relationships<'a, 'r> {
+    pub(crate) fn new(link: &'a SubredditLink<'r>) -> Self {
+        Self { link }
+    }
+
+    fn reddit(&self) -> &'r Reddit {
+        self.link.reddit
+    }
+
+    /// Adds `user` to a relationship list.
+    pub(crate) async fn add(&self, send: &SendFriend<'_>) -> io::Result<()> {
+        let ep = self.reddit().ep(endpoints::FRIEND.subreddit(self.link.name()))?;
+        self.reddit().post_action(ep, send).await
+    }
+
+    /// Removes `user` from a relationship list.
+    pub async fn remove(&self, user: &str, relationship: RelationshipType) -> io::Result<()> {
+        let ep = self.reddit().ep(endpoints::UNFRIEND.subreddit(self.link.name()))?;
+        self.reddit()
+            .post_action(ep, &SendUnfriend {
+                name: user,
+                relationship: relationship.to_str(),
+            })
+            .await
+    }
+
+    pub async fn ban(&self, user: &str, options: &BanOptions) -> io::Result<()> {
+        let mut send = SendFriend::new(user, RelationshipType::Banned);
+        options.apply(&mut send)?;
+        self.add(&send).await
+    }
+
+    pub async fn unban(&self, user: &str) -> io::Result<()> {
+        self.remove(user, RelationshipType::Banned).await
+    }
+
+    /// Stops `user` from sending modmail to the subreddit.
+    pub async fn mute(&self, user: &str, note: Option<&str>) -> io::Result<()> {
+        let mut send = SendFriend::new(user, RelationshipType::Muted);
+        send.note = note;
+        self.add(&send).await
+    }
+
+    pub async fn unmute(&self, user: &str) -> io::Result<()> {
+        self.remove(user, RelationshipType::Muted).await
+    }
+
+    /// Adds `user` as an approved user.
+    pub async fn add_contributor(&self, user: &str) -> io::Result<()> {
+        self.add(&SendFriend::new(user, RelationshipType::Contributor))
+            .await
+    }
+
+    pub async fn remove_contributor(&self, user: &str) -> io::Result<()> {
+        self.remove(user, RelationshipType::Contributor).await
+    }
+
+    /// Stops `user` from editing the wiki.
+    /// Only the duration and note of `options` are used,
+    /// the reason, message and context are ignored.
+    pub async fn wiki_ban(&self, user: &str, options: &BanOptions) -> io::Result<()> {
+        let mut send = SendFriend::new(user, RelationshipType::WikiBanned);
+        options.apply_wiki(&mut send)?;
+        self.add(&send).await
+    }
+
+    pub async fn wiki_unban(&self, user: &str) -> io::Result<()> {
+        self.remove(user, RelationshipType::WikiBanned).await
+    }
+
+    pub async fn add_wiki_contributor(&self, user: &str) -> io::Result<()> {
+        self.add(&SendFriend::new(user, RelationshipType::WikiContributor))
+            .await
+    }
+
+    pub async fn remove_wiki_contributor(&self, user: &str) -> io::Result<()> {
+        self.remove(user, RelationshipType::WikiContributor).await
+    }
+
+    /// First page of a relationship list.
+    /// Moderators are listed with [SubredditLink::moderators] instead.
+    pub async fn list(&self, relationship: RelationshipType) -> io::Result<Listing<'r, RelatedUser<'r>>> {
+        let builder = list_endpoint(relationship).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} can not be listed as a relationship.", relationship.to_str()),
+            )
+        })?;
+
+        let ep = self
+            .reddit()
+            .ep(builder.subreddit(self.link.name()))?
+            .add_query_pairs(&[("limit", "100")]);
+        Listing::new_listing(self.reddit(), ep).await
+    }
+
+    pub async fn banned(&self) -> io::Result<Listing<'r, RelatedUser<'r>>> {
+        self.list(RelationshipType::Banned).await
+    }
+
+    pub async fn muted(&self) -> io::Result<Listing<'r, RelatedUser<'r>>> {
+        self.list(RelationshipType::Muted).await
+    }
+
+    /// Approved users.
+    pub async fn contributors(&self) -> io::Result<Listing<'r, RelatedUser<'r>>> {
+        self.list(RelationshipType::Contributor).await
+    }
+
+    pub async fn wiki_banned(&self) -> io::Result<Listing<'r, RelatedUser<'r>>> {
+        self.list(RelationshipType::WikiBanned).await
+    }
+
+    pub async fn wiki_contributors(&self) -> io::Result<Listing<'r, RelatedUser<'r>>> {
+        self.list(RelationshipType::WikiContributor).await
+    }
+}
+
+fn list_endpoint(relationship: RelationshipType) -> Option<EndpointBuilder> {
+    Some(match relationship {
+        RelationshipType::Banned => endpoints::LIST_BANNED,
+        RelationshipType::Muted => endpoints::LIST_MUTED,
+        RelationshipType::Contributor => endpoints::LIST_CONTRIBUTOR,
+        RelationshipType::WikiBanned => endpoints::LIST_WIKIBANNED,
+        RelationshipType::WikiContributor => endpoints::LIST_WIKICONTRIBUTOR,
+        RelationshipType::Moderator | RelationshipType::ModeratorInvite => return None,
+    })
+}
+
+/// A user in a subreddits relationship list.
+pub struct RelatedUser<'r> {
+    reddit: &'r Reddit,
+    info: RelationshipInfo,
+}
+
+impl<'r> RelatedUser<'r> {
+    pub fn info(&self) -> &RelationshipInfo {
+        &self.info
+    }
+
+    pub fn name(&self) -> &str {
+        &self.info.name
+    }
+
+    /// When the user was added to the list.
+    pub fn date(&self) -> DateTime<Utc> {
+        self.info.date
+    }
+
+    pub fn note(&self) -> Option<&str> {
+        self.info.note.as_deref()
+    }
+
+    /// Days left on a temporary ban.
+    pub fn days_left(&self) -> Option<u32> {
+        self.info.days_left
+    }
+
+    pub fn user(&self) -> RedditUserLink<'r> {
+        self.reddit.user(&self.info.name)
+    }
+}
+
+impl<'r> AbstractedApi<'r> for RelatedUser<'r> {
+    type ApiType = RelationshipInfo;
+    type AbstractedType = RelatedUser<'r>;
+
+    fn from_parent(reddit: &'r Reddit, info: Self::ApiType) -> RelatedUser<'r> {
+        RelatedUser { reddit, info }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn form(send: &SendFriend<'_>) -> String {
+        serde_urlencoded::to_string(send).unwrap()
+    }
+
+    fn full_ban() -> BanOptions {
+        BanOptions::new()
+            .duration(7)
+            .reason("Spam")
+            .note("third strike")
+            .message("Please read the rules")
+            .context("t3_gkxqzq")
+    }
+
+    #[test]
+    fn ban_duration_bounds() {
+        for days in &[1, 7, BAN_DURATION_MAX] {
+            let options = BanOptions::new().duration(*days);
+            let mut send = SendFriend::new("a_user", RelationshipType::Banned);
+            assert!(options.apply(&mut send).is_ok(), "{} days", days);
+            assert_eq!(send.duration, Some(*days));
+        }
+
+        for days in &[0, BAN_DURATION_MAX + 1] {
+            let options = BanOptions::new().duration(*days);
+            let mut send = SendFriend::new("a_user", RelationshipType::Banned);
+            let err = options.apply(&mut send).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput, "{} days", days);
+        }
+    }
+
+    #[test]
+    fn permanent_ban() {
+        let options = BanOptions::new().reason("Spam");
+        let mut send = SendFriend::new("a_user", RelationshipType::Banned);
+        options.apply(&mut send).unwrap();
+        assert_eq!(form(&send), "name=a_user&type=banned&ban_reason=Spam");
+    }
+
+    #[test]
+    fn ban_sends_every_field() {
+        let options = full_ban();
+        let mut send = SendFriend::new("a_user", RelationshipType::Banned);
+        options.apply(&mut send).unwrap();
+        assert_eq!(
+            form(&send),
+            "name=a_user&type=banned&duration=7&note=third+strike&ban_reason=Spam\
+             &ban_message=Please+read+the+rules&ban_context=t3_gkxqzq"
+        );
+    }
+
+    #[test]
+    fn wiki_ban_sends_duration_and_note() {
+        let options = full_ban();
+        let mut send = SendFriend::new("a_user", RelationshipType::WikiBanned);
+        options.apply_wiki(&mut send).unwrap();
+        assert_eq!(
+            form(&send),
+            "name=a_user&type=wikibanned&duration=7&note=third+strike"
+        );
+
+        let options = BanOptions::new().duration(0);
+        let mut send = SendFriend::new("a_user", RelationshipType::WikiBanned);
+        assert!(options.apply_wiki(&mut send).is_err());
+    }
+}
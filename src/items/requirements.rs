@@ -0,0 +1,401 @@
+//! Checks a post against [PostRequirements] before it is submitted.
+use crate::items::subreddit::{SubmitOptions, SubredditSubmission};
+use crate::models::{
+    BodyRestrictionPolicy, GalleryRequirement, LinkRestrictionPolicy, PostRequirementViolation,
+    PostRequirements,
+};
+
+use regex::Regex;
+use reqwest::Url;
+
+fn contains_ignore_case(text: &str, needle: &str) -> bool {
+    text.to_lowercase().contains(&needle.to_lowercase())
+}
+
+/// True if `domain` is `entry` or a subdomain of it.
+fn domain_matches(domain: &str, entry: &str) -> bool {
+    let entry = entry.trim_start_matches("www.").to_lowercase();
+    domain == entry || domain.ends_with(&format!(".{}", entry))
+}
+
+/// True if there are required strings and `text` has none of them.
+fn missing_required(text: &str, required: &[String]) -> bool {
+    !required.is_empty() && !required.iter().any(|s| contains_ignore_case(text, s))
+}
+
+fn blacklisted_in<'a>(text: &'a str, blacklisted: &'a [String]) -> impl Iterator<Item = String> + 'a {
+    blacklisted
+        .iter()
+        .filter(move |s| contains_ignore_case(text, s))
+        .cloned()
+}
+
+/// True if there are valid regexes and `text` matches none of them.
+fn regex_mismatch(text: &str, regexes: &[String]) -> bool {
+    let compiled: Vec<Regex> = regexes.iter().filter_map(|r| Regex::new(r).ok()).collect();
+    !compiled.is_empty() && !compiled.iter().any(|r| r.is_match(text))
+}
+
+impl PostRequirements {
+    /// Checks a post against the requirements without sending anything.
+    /// Reposts can only be found by [SubredditLink::check_submission](crate::items::SubredditLink::check_submission).
+    pub fn check(
+        &self,
+        title: &str,
+        submission: &SubredditSubmission<'_>,
+        options: &SubmitOptions,
+    ) -> Vec<PostRequirementViolation> {
+        use PostRequirementViolation::*;
+        let mut out = Vec::new();
+
+        let title_len = title.chars().count();
+        if let Some(min) = self.title_text_min_length {
+            if title_len < min {
+                out.push(TitleTooShort { min, len: title_len });
+            }
+        }
+        if let Some(max) = self.title_text_max_length {
+            if title_len > max {
+                out.push(TitleTooLong { max, len: title_len });
+            }
+        }
+        if missing_required(title, &self.title_required_strings) {
+            out.push(TitleMissingRequiredString(self.title_required_strings.clone()));
+        }
+        out.extend(blacklisted_in(title, &self.title_blacklisted_strings).map(TitleBlacklistedString));
+        if regex_mismatch(title, &self.title_regexes) {
+            out.push(TitleRegexMismatch(self.title_regexes.clone()));
+        }
+
+        let body = match submission {
+            SubredditSubmission::Text(body) => Some(*body),
+            SubredditSubmission::Poll { text, .. } => Some(*text),
+            _ => None,
+        };
+        if let Some(body) = body {
+            let body_len = body.chars().count();
+            match self.body_restriction_policy {
+                BodyRestrictionPolicy::Required if body.trim().is_empty() => out.push(BodyRequired),
+                BodyRestrictionPolicy::NotAllowed if !body.trim().is_empty() => out.push(BodyNotAllowed),
+                _ => {}
+            }
+            if let Some(min) = self.body_text_min_length {
+                if body_len < min {
+                    out.push(BodyTooShort { min, len: body_len });
+                }
+            }
+            if let Some(max) = self.body_text_max_length {
+                if body_len > max {
+                    out.push(BodyTooLong { max, len: body_len });
+                }
+            }
+            if missing_required(body, &self.body_required_strings) {
+                out.push(BodyMissingRequiredString(self.body_required_strings.clone()));
+            }
+            out.extend(blacklisted_in(body, &self.body_blacklisted_strings).map(BodyBlacklistedString));
+            if regex_mismatch(body, &self.body_regexes) {
+                out.push(BodyRegexMismatch(self.body_regexes.clone()));
+            }
+        }
+
+        if let SubredditSubmission::Link(link) = submission {
+            let domain = Url::parse(link)
+                .ok()
+                .and_then(|u| u.host_str().map(|h| h.trim_start_matches("www.").to_lowercase()));
+            if let Some(domain) = domain {
+                match self.link_restriction_policy {
+                    LinkRestrictionPolicy::Whitelist
+                        if !self.domain_whitelist.iter().any(|d| domain_matches(&domain, d)) =>
+                    {
+                        out.push(DomainNotWhitelisted(domain))
+                    }
+                    LinkRestrictionPolicy::Blacklist
+                        if self.domain_blacklist.iter().any(|d| domain_matches(&domain, d)) =>
+                    {
+                        out.push(DomainBlacklisted(domain))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if let SubredditSubmission::Gallery(items) = submission {
+            if let Some(min) = self.gallery_min_items {
+                if items.len() < min {
+                    out.push(GalleryTooFewItems { min, len: items.len() });
+                }
+            }
+            if let Some(max) = self.gallery_max_items {
+                if items.len() > max {
+                    out.push(GalleryTooManyItems { max, len: items.len() });
+                }
+            }
+            let is_empty = |s: &Option<String>| s.as_deref().map(str::is_empty).unwrap_or(true);
+            if self.gallery_captions_requirement == GalleryRequirement::Required
+                && items.iter().any(|i| is_empty(&i.caption))
+            {
+                out.push(GalleryCaptionRequired);
+            }
+            if self.gallery_urls_requirement == GalleryRequirement::Required
+                && items.iter().any(|i| is_empty(&i.outbound_url))
+            {
+                out.push(GalleryUrlRequired);
+            }
+        }
+
+        if self.is_flair_required && options.flair_id.is_none() {
+            out.push(FlairRequired);
+        }
+
+        out
+    }
+}
+
+// `Repost` is only found by `check_submission`, which searches reddit.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::media::{GalleryItem, MediaFile};
+    use PostRequirementViolation::*;
+
+    fn text(reqs: &PostRequirements, title: &str, body: &str) -> Vec<PostRequirementViolation> {
+        reqs.check(
+            title,
+            &SubredditSubmission::Text(body),
+            &SubmitOptions::new(),
+        )
+    }
+
+    fn link(reqs: &PostRequirements, url: &str) -> Vec<PostRequirementViolation> {
+        reqs.check(
+            "title",
+            &SubredditSubmission::Link(url),
+            &SubmitOptions::new(),
+        )
+    }
+
+    fn gallery(reqs: &PostRequirements, items: &[GalleryItem]) -> Vec<PostRequirementViolation> {
+        reqs.check(
+            "title",
+            &SubredditSubmission::Gallery(items),
+            &SubmitOptions::new(),
+        )
+    }
+
+    fn strings(s: &[&str]) -> Vec<String> {
+        s.iter().map(|s| (*s).to_owned()).collect()
+    }
+
+    fn image() -> GalleryItem {
+        GalleryItem::new(MediaFile::new("a.png", "image/png", Vec::new()))
+    }
+
+    #[test]
+    fn no_requirements() {
+        let reqs = PostRequirements::default();
+        assert!(text(&reqs, "title", "").is_empty());
+        assert!(link(&reqs, "https://example.com").is_empty());
+        assert!(gallery(&reqs, &[image()]).is_empty());
+    }
+
+    #[test]
+    fn title_length() {
+        let reqs = PostRequirements {
+            title_text_min_length: Some(5),
+            title_text_max_length: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(text(&reqs, "abcd", ""), [TitleTooShort { min: 5, len: 4 }]);
+        assert_eq!(
+            text(&reqs, "abcdefghijk", ""),
+            [TitleTooLong { max: 10, len: 11 }]
+        );
+        assert!(text(&reqs, "ééééé", "").is_empty());
+    }
+
+    #[test]
+    fn title_strings() {
+        let reqs = PostRequirements {
+            title_required_strings: strings(&["[Question]", "[Help]"]),
+            title_blacklisted_strings: strings(&["urgent", "please"]),
+            ..Default::default()
+        };
+        assert_eq!(
+            text(&reqs, "Something", ""),
+            [TitleMissingRequiredString(strings(&[
+                "[Question]",
+                "[Help]"
+            ]))]
+        );
+        assert!(text(&reqs, "[help] something", "").is_empty());
+        assert_eq!(
+            text(&reqs, "[Help] URGENT, please", ""),
+            [
+                TitleBlacklistedString("urgent".to_owned()),
+                TitleBlacklistedString("please".to_owned())
+            ]
+        );
+    }
+
+    #[test]
+    fn title_regex() {
+        let reqs = PostRequirements {
+            title_regexes: strings(&[r"^\[\w+\]", "(invalid"]),
+            ..Default::default()
+        };
+        assert_eq!(
+            text(&reqs, "no tag", ""),
+            [TitleRegexMismatch(strings(&[r"^\[\w+\]", "(invalid"]))]
+        );
+        assert!(text(&reqs, "[tag] title", "").is_empty());
+
+        let only_invalid = PostRequirements {
+            title_regexes: strings(&["(invalid"]),
+            ..Default::default()
+        };
+        assert!(text(&only_invalid, "anything", "").is_empty());
+    }
+
+    #[test]
+    fn body_policy() {
+        let required = PostRequirements {
+            body_restriction_policy: BodyRestrictionPolicy::Required,
+            ..Default::default()
+        };
+        assert_eq!(text(&required, "title", "  "), [BodyRequired]);
+        assert!(text(&required, "title", "body").is_empty());
+
+        let not_allowed = PostRequirements {
+            body_restriction_policy: BodyRestrictionPolicy::NotAllowed,
+            ..Default::default()
+        };
+        assert_eq!(text(&not_allowed, "title", "body"), [BodyNotAllowed]);
+        assert!(text(&not_allowed, "title", "").is_empty());
+        assert!(link(&not_allowed, "https://example.com").is_empty());
+    }
+
+    #[test]
+    fn body_length() {
+        let reqs = PostRequirements {
+            body_text_min_length: Some(3),
+            body_text_max_length: Some(5),
+            ..Default::default()
+        };
+        assert_eq!(
+            text(&reqs, "title", "ab"),
+            [BodyTooShort { min: 3, len: 2 }]
+        );
+        assert_eq!(
+            text(&reqs, "title", "abcdef"),
+            [BodyTooLong { max: 5, len: 6 }]
+        );
+        assert!(text(&reqs, "title", "abcd").is_empty());
+    }
+
+    #[test]
+    fn body_strings_and_regex() {
+        let reqs = PostRequirements {
+            body_required_strings: strings(&["version"]),
+            body_blacklisted_strings: strings(&["http://"]),
+            body_regexes: strings(&[r"\d+\.\d+"]),
+            ..Default::default()
+        };
+        assert_eq!(
+            text(&reqs, "title", "nothing"),
+            [
+                BodyMissingRequiredString(strings(&["version"])),
+                BodyRegexMismatch(strings(&[r"\d+\.\d+"]))
+            ]
+        );
+        assert_eq!(
+            text(&reqs, "title", "Version 1.2 at http://example.com"),
+            [BodyBlacklistedString("http://".to_owned())]
+        );
+
+        let poll = SubredditSubmission::Poll {
+            text: "nothing",
+            options: &["a", "b"],
+            duration: 1,
+        };
+        assert_eq!(reqs.check("title", &poll, &SubmitOptions::new()).len(), 2);
+    }
+
+    #[test]
+    fn domain_whitelist() {
+        let reqs = PostRequirements {
+            link_restriction_policy: LinkRestrictionPolicy::Whitelist,
+            domain_whitelist: strings(&["www.github.com", "rust-lang.org"]),
+            ..Default::default()
+        };
+        assert!(link(&reqs, "https://github.com/rust-lang/rust").is_empty());
+        assert!(link(&reqs, "https://blog.rust-lang.org/").is_empty());
+        assert_eq!(
+            link(&reqs, "https://www.example.com/"),
+            [DomainNotWhitelisted("example.com".to_owned())]
+        );
+        assert_eq!(
+            link(&reqs, "https://notgithub.com/"),
+            [DomainNotWhitelisted("notgithub.com".to_owned())]
+        );
+    }
+
+    #[test]
+    fn domain_blacklist() {
+        let reqs = PostRequirements {
+            link_restriction_policy: LinkRestrictionPolicy::Blacklist,
+            domain_blacklist: strings(&["example.com"]),
+            ..Default::default()
+        };
+        assert_eq!(
+            link(&reqs, "https://cdn.Example.com/x"),
+            [DomainBlacklisted("cdn.example.com".to_owned())]
+        );
+        assert!(link(&reqs, "https://github.com").is_empty());
+    }
+
+    #[test]
+    fn flair_required() {
+        let reqs = PostRequirements {
+            is_flair_required: true,
+            ..Default::default()
+        };
+        assert_eq!(text(&reqs, "title", ""), [FlairRequired]);
+
+        let options = SubmitOptions::new().flair_id("flair-template");
+        let submission = SubredditSubmission::Text("");
+        assert!(reqs.check("title", &submission, &options).is_empty());
+    }
+
+    #[test]
+    fn gallery_items() {
+        let reqs = PostRequirements {
+            gallery_min_items: Some(2),
+            gallery_max_items: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(
+            gallery(&reqs, &[image()]),
+            [GalleryTooFewItems { min: 2, len: 1 }]
+        );
+        assert_eq!(
+            gallery(&reqs, &vec![image(); 4]),
+            [GalleryTooManyItems { max: 3, len: 4 }]
+        );
+        assert!(gallery(&reqs, &[image(), image()]).is_empty());
+    }
+
+    #[test]
+    fn gallery_captions_and_urls() {
+        let reqs = PostRequirements {
+            gallery_captions_requirement: GalleryRequirement::Required,
+            gallery_urls_requirement: GalleryRequirement::Required,
+            ..Default::default()
+        };
+        let complete = image().caption("a cat").outbound_url("https://example.com");
+        assert!(gallery(&reqs, std::slice::from_ref(&complete)).is_empty());
+        assert_eq!(
+            gallery(&reqs, &[complete, image().caption("")]),
+            [GalleryCaptionRequired, GalleryUrlRequired]
+        );
+    }
+}
@@ -37,8 +37,8 @@ impl<'r, 's, T: AbstractedApi<'r>> RedditSearch<'r, 's, T> {
     ) -> io::Result<RedditSearch<'r, 's, T>> {
         let params = Rc::from(SearchParams {
             reddit: parent,
-            query: query,
-            sort: sort,
+            query,
+            sort,
             endpoint: search_ep,
         });
 
@@ -68,8 +68,8 @@ impl<'r, 's, T: AbstractedApi<'r>> RedditSearch<'r, 's, T> {
         };
 
         Ok(RedditSearch::<'r, 's> {
-            params: params,
-            results: results,
+            params,
+            results,
             before: search.before,
             after: search.after,
         })
@@ -1,49 +1,206 @@
-use crate::models::{CommentData, ListingData, PostInfo, SendComment};
+use crate::models::{CommentData, CommentListing, ListingData, PostInfo, ReportReason, VoteDirection};
 
 use crate::reddit::Reddit;
 
-use crate::items::{post::Post, user::RedditUserLink, AbstractedApi};
+use crate::items::{moderation::CommentModeration, forest::{BreadthFirst, CommentForest, DepthFirst}, post::Post, user::RedditUserLink, AbstractedApi};
+use chrono::{DateTime, Utc};
+use regex::Regex;
 use std::io;
-use crate::endpoints;
+use crate::endpoints::{CommentSort, Endpoint};
+
+/// Options for loading a [Submission].
+/// Anything left as `None` uses reddit's default.
+/// ```no_run
+/// # use snoo::{CommentSort, Reddit, SubmissionOptions};
+/// # async fn example(r: Reddit) -> std::io::Result<()> {
+/// let options = SubmissionOptions::new().sort(CommentSort::New).limit(50).depth(3);
+/// let s = r.submission_with("gkxqzq", &options).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default, Clone, Debug)]
+pub struct SubmissionOptions {
+    sort: Option<CommentSort>,
+    limit: Option<u32>,
+    depth: Option<u32>,
+    comment: Option<String>,
+    context: Option<u32>,
+    threaded: Option<bool>,
+}
+
+impl SubmissionOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn sort(mut self, sort: CommentSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Max number of comments to load.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Max depth of the comment tree.
+    pub fn depth(mut self, depth: u32) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// Only load the thread of the comment with id `comment_id`.
+    pub fn comment(mut self, comment_id: &str) -> Self {
+        self.comment = Some(comment_id.trim_start_matches("t1_").to_owned());
+        self
+    }
+
+    /// Number of parents to load above the focused comment (0-8).
+    pub fn context(mut self, context: u32) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    /// If false, comments are returned as a flat list instead of a tree.
+    pub fn threaded(mut self, threaded: bool) -> Self {
+        self.threaded = Some(threaded);
+        self
+    }
+
+    pub(crate) fn comment_sort(&self) -> Option<CommentSort> {
+        self.sort
+    }
+
+    pub(crate) fn apply(&self, ep: Endpoint) -> Endpoint {
+        let limit = self.limit.map(|l| l.to_string());
+        let depth = self.depth.map(|d| d.to_string());
+        let context = self.context.map(|c| c.to_string());
+
+        let mut pairs = Vec::new();
+        if let Some(sort) = self.sort {
+            pairs.push(("sort", sort.to_str()));
+        }
+        if let Some(limit) = &limit {
+            pairs.push(("limit", limit.as_str()));
+        }
+        if let Some(depth) = &depth {
+            pairs.push(("depth", depth.as_str()));
+        }
+        if let Some(comment) = &self.comment {
+            pairs.push(("comment", comment.as_str()));
+        }
+        if let Some(context) = &context {
+            pairs.push(("context", context.as_str()));
+        }
+        if let Some(threaded) = self.threaded {
+            pairs.push(("threaded", if threaded { "true" } else { "false" }));
+        }
+        ep.add_query_pairs(&pairs)
+    }
+}
 
 /// A submission is a full reddit post
-/// It is a [Post] with a list of [Comment]s
+/// It is a [Post] with a tree of [Comment]s
 pub struct Submission<'r> {
     op: Post<'r>,
-    comments: Vec<Comment<'r>>,
+    comments: CommentForest<'r>,
 }
 
 impl<'r> Submission<'r> {
-    pub(crate) fn from_resp(reddit: &'r Reddit, mut op: ListingData<PostInfo>, comments: ListingData<CommentData>) -> Self {
+    pub(crate) fn from_resp(
+        reddit: &'r Reddit,
+        mut op: ListingData<PostInfo>,
+        comments: CommentListing,
+        sort: Option<CommentSort>,
+    ) -> Self {
+        let op = reddit.bind::<Post>(op.children.swap_remove(0).data);
+        let comments = CommentForest::new(reddit, op.name(), op.name(), sort, comments.children);
 
         Self {
-            op: reddit.bind::<Post>(op.children.swap_remove(0).data),
-            comments: comments.inner_children().iter().map(|e| reddit.bind::<Comment>(e.clone())).collect(),
+            op,
+            comments,
         }
     }
 
-    pub fn op(&self) -> &Post {
+    pub fn op(&self) -> &Post<'_> {
         &self.op
     }
 
-    pub fn comments(&self) -> &[Comment] {
+    /// The top level comments.
+    pub fn comments(&self) -> &[Comment<'_>] {
+        self.comments.comments()
+    }
+
+    /// The full comment tree.
+    pub fn forest(&self) -> &CommentForest<'r> {
         &self.comments
     }
+
+    pub fn forest_mut(&mut self) -> &mut CommentForest<'r> {
+        &mut self.comments
+    }
+
+    /// See [CommentForest::dfs]
+    pub fn dfs<'a>(&'a self) -> DepthFirst<'a, 'r> {
+        self.comments.dfs()
+    }
+
+    /// See [CommentForest::bfs]
+    pub fn bfs<'a>(&'a self) -> BreadthFirst<'a, 'r> {
+        self.comments.bfs()
+    }
+
+    /// Every loaded comment in depth first order.
+    pub fn flatten(&self) -> Vec<&Comment<'r>> {
+        self.comments.flatten()
+    }
+
+    /// Finds a loaded comment by its fullname (`t1_...`).
+    pub fn find_comment(&self, name: &str) -> Option<&Comment<'r>> {
+        self.comments.find(name)
+    }
+
+    /// Depth of a loaded comment, top level comments have a depth of 0.
+    pub fn depth_of(&self, comment: &Comment<'_>) -> Option<usize> {
+        self.comments.depth_of(comment.name())
+    }
+
+    /// The comment `comment` is replying to.
+    /// None if it is a top level comment.
+    pub fn parent_of(&self, comment: &Comment<'_>) -> Option<&Comment<'r>> {
+        self.comments.parent_of(comment)
+    }
+
+    pub fn comments_by(&self, author: &str) -> Vec<&Comment<'r>> {
+        self.comments.by_author(author)
+    }
+
+    pub fn comments_matching(&self, pattern: &Regex) -> Vec<&Comment<'r>> {
+        self.comments.matching(pattern)
+    }
+
+    /// Loads comments that reddit left out of the tree.
+    /// See [CommentForest::load_more]
+    pub async fn load_more_comments(&mut self, request_limit: Option<usize>) -> io::Result<usize> {
+        self.comments.load_more(request_limit).await
+    }
 }
 
 /// A user comment
 pub struct Comment<'r> {
-    reddit: &'r Reddit,
+    pub(crate) reddit: &'r Reddit,
     data: CommentData,
+    replies: CommentForest<'r>,
 }
 
-impl Comment<'_> {
+impl<'r> Comment<'r> {
     /// Returns the underlying [CommentData] model.
     pub fn info(&self) -> &CommentData {
         &self.data
     }
 
-    pub fn author(&self) -> RedditUserLink {
+    pub fn author(&self) -> RedditUserLink<'_> {
         self.reddit.user(&self.data.author)
     }
 
@@ -55,12 +212,130 @@ impl Comment<'_> {
         self.data.body.as_str()
     }
 
-    pub async fn reply(&self, message: &str) -> io::Result<()> {
-        let target_url = self.reddit.ep(endpoints::COMMENT)?;
-        self.reddit.post_data(target_url, &SendComment{
-            thing_id: self.name(),
-            text: message,
-        }).await
+    /// Fullname of the post or comment this is replying to.
+    pub fn parent_id(&self) -> &str {
+        &self.data.parent_id
+    }
+
+    /// True if this is replying directly to the post.
+    pub fn is_top_level(&self) -> bool {
+        self.data.parent_id == self.data.link_id
+    }
+
+    pub fn replies(&self) -> &CommentForest<'r> {
+        &self.replies
+    }
+
+    /// Walks this comment and its loaded replies depth first.
+    /// This comment has a depth of 0.
+    pub fn dfs<'a>(&'a self) -> DepthFirst<'a, 'r> {
+        DepthFirst::new(vec![(0, self)])
+    }
+
+    /// Walks this comment and its loaded replies one level at a time.
+    /// This comment has a depth of 0.
+    pub fn bfs<'a>(&'a self) -> BreadthFirst<'a, 'r> {
+        BreadthFirst::new(vec![(0, self)])
+    }
+
+    /// This comment followed by all of its loaded replies, depth first.
+    pub fn flatten(&self) -> Vec<&Comment<'r>> {
+        self.dfs().map(|(_, c)| c).collect()
+    }
+
+    /// Finds a loaded reply by its fullname (`t1_...`).
+    pub fn find_reply(&self, name: &str) -> Option<&Comment<'r>> {
+        self.replies.find(name)
+    }
+
+    pub fn replies_by(&self, author: &str) -> Vec<&Comment<'r>> {
+        self.replies.by_author(author)
+    }
+
+    pub fn replies_matching(&self, pattern: &Regex) -> Vec<&Comment<'r>> {
+        self.replies.matching(pattern)
+    }
+
+    pub fn replies_mut(&mut self) -> &mut CommentForest<'r> {
+        &mut self.replies
+    }
+
+    /// When the comment was posted.
+    pub fn created(&self) -> DateTime<Utc> {
+        self.data.created
+    }
+
+    /// Same as `created`, read from the `created_utc` field.
+    pub fn created_utc(&self) -> DateTime<Utc> {
+        self.data.created_utc
+    }
+
+    /// When the comment was last edited, if it has been.
+    pub fn edited(&self) -> Option<DateTime<Utc>> {
+        self.data.moderate_data.edited.time()
+    }
+
+    /// Moderator actions for the comment.
+    pub fn moderation(&self) -> CommentModeration<'_, 'r> {
+        CommentModeration::new(self)
+    }
+
+    /// Replies to the comment and returns the new [Comment].
+    pub async fn reply(&self, message: &str) -> io::Result<Comment<'r>> {
+        self.reddit.comment(self.name(), message).await
+    }
+
+    /// Votes on the comment and updates [VoteData](crate::models::VoteData).
+    /// Fails with [SnooError::Archived](crate::SnooError::Archived) if the comment is archived.
+    pub async fn vote(&mut self, direction: VoteDirection) -> io::Result<()> {
+        self.reddit.vote(&self.data.moderate_data, direction).await?;
+        self.data.vote_data.apply_vote(direction);
+        Ok(())
+    }
+
+    /// Edits the body of the comment.
+    /// Returns the updated [CommentData], which also replaces the one in this [Comment].
+    pub async fn edit(&mut self, new_text: &str) -> io::Result<CommentData> {
+        let data = self.reddit.edit_text::<CommentData>(self.name(), new_text).await?;
+        self.data = data.clone();
+        Ok(data)
+    }
+
+    /// Reports the comment to the subreddits moderators.
+    /// The available reasons can be found with [SubredditLink::rules](crate::SubredditLink::rules).
+    pub async fn report(&self, reason: ReportReason<'_>) -> io::Result<()> {
+        self.reddit.report(self.name(), reason).await
+    }
+
+    /// Deletes the comment.
+    pub async fn delete(&self) -> io::Result<()> {
+        self.reddit.delete(self.name()).await
+    }
+
+    /// Saves the comment.
+    /// `category` can only be used by accounts with reddit premium.
+    pub async fn save(&mut self, category: Option<&str>) -> io::Result<()> {
+        self.reddit.save(self.name(), category).await?;
+        self.data.moderate_data.saved = true;
+        Ok(())
+    }
+
+    pub async fn unsave(&mut self) -> io::Result<()> {
+        self.reddit.unsave(self.name()).await?;
+        self.data.moderate_data.saved = false;
+        Ok(())
+    }
+
+    pub async fn upvote(&mut self) -> io::Result<()> {
+        self.vote(VoteDirection::Up).await
+    }
+
+    pub async fn downvote(&mut self) -> io::Result<()> {
+        self.vote(VoteDirection::Down).await
+    }
+
+    pub async fn clear_vote(&mut self) -> io::Result<()> {
+        self.vote(VoteDirection::Clear).await
     }
 }
 
@@ -68,10 +343,68 @@ impl<'r> AbstractedApi<'r> for Comment<'r> {
     type AbstractedType = Comment<'r>;
     type ApiType = CommentData;
 
-    fn from_parent(parent: &'r Reddit, info: Self::ApiType) -> Self {
+    fn from_parent(parent: &'r Reddit, mut info: Self::ApiType) -> Self {
+        let replies = std::mem::take(&mut info.replies).into_nodes();
         Self {
             reddit: parent,
+            replies: CommentForest::new(parent, &info.link_id, &info.moderate_data.name, None, replies),
             data: info,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints;
+
+    fn query(options: &SubmissionOptions) -> Vec<(String, String)> {
+        let ep = endpoints::SUBMISSION.id("gkxqzq").oauth_ep().unwrap();
+        options.apply(ep).to_url().query_pairs().into_owned().collect()
+    }
+
+    fn pairs(expected: &[(&str, &str)]) -> Vec<(String, String)> {
+        expected
+            .iter()
+            .map(|(k, v)| ((*k).to_owned(), (*v).to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn default_options_add_nothing() {
+        assert!(query(&SubmissionOptions::new()).is_empty());
+    }
+
+    #[test]
+    fn options_query() {
+        let options = SubmissionOptions::new()
+            .sort(CommentSort::Top)
+            .limit(50)
+            .depth(3)
+            .comment("t1_fqxyz12")
+            .context(2)
+            .threaded(false);
+
+        assert_eq!(
+            query(&options),
+            pairs(&[
+                ("sort", "top"),
+                ("limit", "50"),
+                ("depth", "3"),
+                ("comment", "fqxyz12"),
+                ("context", "2"),
+                ("threaded", "false"),
+            ])
+        );
+        assert_eq!(options.comment_sort(), Some(CommentSort::Top));
+    }
+
+    #[test]
+    fn comment_id_without_prefix() {
+        let options = SubmissionOptions::new().comment("fqxyz12").threaded(true);
+        assert_eq!(
+            query(&options),
+            pairs(&[("comment", "fqxyz12"), ("threaded", "true")])
+        );
+    }
+}
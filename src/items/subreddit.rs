@@ -1,27 +1,76 @@
-use crate::endpoints::{self, SearchSort};
+use crate::endpoints::{self, Endpoint, ModQueue, QueueOnly, SearchSort};
+use crate::error::SnooError;
 use crate::feed::ContentStream;
 use crate::reddit::Reddit;
 
-use crate::items::{post::Post, search::PostSearch, AbstractedApi};
+use crate::items::{
+    listing::Listing,
+    media::{GalleryItem, MediaFile},
+    modlog::{ModLogEntry, ModLogOptions},
+    post::Post,
+    relationship::Relationships,
+    search::PostSearch,
+    thing::Thing,
+    AbstractedApi,
+};
 use crate::models::{
+    ModAction,
     PostInfo, 
+    PostRequirementViolation,
+    PostRequirements,
+    RemovalReason,
+    RemovalReasonCreated,
+    RemovalReasons,
+    SendRemovalReason,
     SubredditInfo, 
+    SubredditRules,
+    ThingData,
     SubredditSubmit,
+    SubredditSubmitCrosspost,
     SubredditSubmitResponse, 
     SubredditSubmitLink, 
-    SubredditSubmitText
+    SubredditSubmitText,
+    SubmitPollPost,
+    SendGalleryItem,
+    SubmitGalleryPost,
+    SubredditSubmitMedia,
+    GALLERY_ITEMS_MAX,
+    GALLERY_ITEMS_MIN,
+    POLL_DURATION_MAX,
+    POLL_DURATION_MIN,
+    POLL_OPTIONS_MAX,
+    POLL_OPTIONS_MIN,
 };
 
 
-use reqwest::Url;
+use reqwest::{Method, Url};
+use serde_json::Value;
 
-use chrono::{prelude::*, DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 
 use std::io;
 
 pub enum SubredditSubmission<'a>{
     Link(&'a str),
-    Text(&'a str)
+    Text(&'a str),
+    /// The fullname of the post to crosspost. e.g. `t3_gkxqzq`
+    Crosspost(&'a str),
+    Image(&'a MediaFile),
+    /// A video with an image shown before it is played.
+    /// If `gif` is true the video is shown as a looping gif with no sound.
+    Video {
+        video: &'a MediaFile,
+        thumbnail: &'a MediaFile,
+        gif: bool,
+    },
+    /// 2-20 images with optional captions and links.
+    Gallery(&'a [GalleryItem]),
+    /// A poll with 2-6 `options` that runs for `duration` days (1-7).
+    Poll {
+        text: &'a str,
+        options: &'a [&'a str],
+        duration: u32,
+    },
 }
 
 impl SubredditSubmission<'_>{
@@ -29,10 +78,255 @@ impl SubredditSubmission<'_>{
         match self{
             SubredditSubmission::Link(_) => "link",
             SubredditSubmission::Text(_) => "self",
+            SubredditSubmission::Crosspost(_) => "crosspost",
+            SubredditSubmission::Image(_) => "image",
+            SubredditSubmission::Video { gif: false, .. } => "video",
+            SubredditSubmission::Video { gif: true, .. } => "videogif",
+            SubredditSubmission::Gallery(_) => "gallery",
+            SubredditSubmission::Poll { .. } => "poll",
+        }
+    }
+
+    /// Checks the submission can be sent without reddit rejecting it.
+    pub fn validate(&self) -> io::Result<()> {
+        if let SubredditSubmission::Gallery(items) = self {
+            if items.len() < GALLERY_ITEMS_MIN || items.len() > GALLERY_ITEMS_MAX {
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "A gallery must have {}-{} items, got {}",
+                        GALLERY_ITEMS_MIN,
+                        GALLERY_ITEMS_MAX,
+                        items.len()
+                    ),
+                ))?;
+            }
+        }
+
+        if let SubredditSubmission::Poll { options, duration, .. } = self {
+            if options.len() < POLL_OPTIONS_MIN || options.len() > POLL_OPTIONS_MAX {
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "A poll must have {}-{} options, got {}",
+                        POLL_OPTIONS_MIN,
+                        POLL_OPTIONS_MAX,
+                        options.len()
+                    ),
+                ))?;
+            }
+            if options.iter().any(|o| o.trim().is_empty()) {
+                Err(io::Error::new(io::ErrorKind::InvalidInput, "Poll options can not be empty"))?;
+            }
+            if *duration < POLL_DURATION_MIN || *duration > POLL_DURATION_MAX {
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "A poll must run for {}-{} days, got {}",
+                        POLL_DURATION_MIN, POLL_DURATION_MAX, duration
+                    ),
+                ))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Options for a new post.
+/// ```no_run
+/// # use snoo::{Reddit, SubmitOptions, SubredditSubmission};
+/// # async fn example(r: Reddit) -> std::io::Result<()> {
+/// let options = SubmitOptions::new().nsfw(true).flair_id("a1b2c3");
+/// r.subreddit("test").submit_with("title", SubredditSubmission::Text("body"), &options).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SubmitOptions {
+    nsfw: bool,
+    spoiler: bool,
+    sendreplies: bool,
+    resubmit: bool,
+    check_requirements: bool,
+    pub(crate) flair_id: Option<String>,
+    flair_text: Option<String>,
+    collection_id: Option<String>,
+    discussion_type: Option<String>,
+}
+
+impl Default for SubmitOptions {
+    fn default() -> Self {
+        Self {
+            nsfw: false,
+            spoiler: false,
+            sendreplies: true,
+            resubmit: true,
+            check_requirements: false,
+            flair_id: None,
+            flair_text: None,
+            collection_id: None,
+            discussion_type: None,
+        }
+    }
+}
+
+impl SubmitOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn nsfw(mut self, nsfw: bool) -> Self {
+        self.nsfw = nsfw;
+        self
+    }
+
+    pub fn spoiler(mut self, spoiler: bool) -> Self {
+        self.spoiler = spoiler;
+        self
+    }
+
+    /// Send replies to the post to the authors inbox. Defaults to true.
+    pub fn sendreplies(mut self, sendreplies: bool) -> Self {
+        self.sendreplies = sendreplies;
+        self
+    }
+
+    /// Allow a link that has already been posted to the subreddit. Defaults to true.
+    pub fn resubmit(mut self, resubmit: bool) -> Self {
+        self.resubmit = resubmit;
+        self
+    }
+
+    /// Check the post against the subreddits [PostRequirements] before sending it.
+    /// If it fails the submit returns [SnooError::PostRequirements].
+    pub fn check_requirements(mut self, check_requirements: bool) -> Self {
+        self.check_requirements = check_requirements;
+        self
+    }
+
+    /// Id of one of the subreddits flair templates.
+    pub fn flair_id(mut self, flair_id: &str) -> Self {
+        self.flair_id = Some(flair_id.to_owned());
+        self
+    }
+
+    /// Flair text, only used if the flair template is editable.
+    pub fn flair_text(mut self, flair_text: &str) -> Self {
+        self.flair_text = Some(flair_text.to_owned());
+        self
+    }
+
+    /// Id of the collection to add the post to.
+    pub fn collection_id(mut self, collection_id: &str) -> Self {
+        self.collection_id = Some(collection_id.to_owned());
+        self
+    }
+
+    /// "CHAT" to make the post a live chat.
+    pub fn discussion_type(mut self, discussion_type: &str) -> Self {
+        self.discussion_type = Some(discussion_type.to_owned());
+        self
+    }
+
+    pub(crate) fn to_submit<'a>(&'a self, kind: &'a str, sr: &'a str, title: &'a str) -> SubredditSubmit<'a> {
+        SubredditSubmit {
+            kind,
+            sr,
+            title,
+            resubmit: self.resubmit,
+            nsfw: self.nsfw,
+            spoiler: self.spoiler,
+            sendreplies: self.sendreplies,
+            flair_id: self.flair_id.as_deref(),
+            flair_text: self.flair_text.as_deref(),
+            collection_id: self.collection_id.as_deref(),
+            discussion_type: self.discussion_type.as_deref(),
+            iden: None,
+            captcha: None,
         }
     }
 }
 
+/// A post that was just submitted.
+///
+/// The post is loaded right after submitting, if that fails the error is kept
+/// in [SubmittedPost::load_error] and it can be loaded later with [SubmittedPost::load].
+pub struct SubmittedPost<'r> {
+    reddit: &'r Reddit,
+    fullname: String,
+    url: String,
+    post: Option<Post<'r>>,
+    load_error: Option<io::Error>,
+    drafts_count: Option<i32>,
+    warnings: Vec<Value>,
+}
+
+impl<'r> SubmittedPost<'r> {
+    pub(crate) fn new(reddit: &'r Reddit, resp: SubredditSubmitResponse) -> Self {
+        SubmittedPost {
+            reddit,
+            fullname: resp.fullname(),
+            url: resp.url,
+            post: None,
+            load_error: None,
+            drafts_count: resp.drafts_count,
+            warnings: resp.warnings,
+        }
+    }
+
+    /// The fullname of the new post. e.g. `t3_gkxqzq`
+    pub fn fullname(&self) -> &str {
+        &self.fullname
+    }
+
+    /// Link to the new post.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The post, None if it has not been loaded.
+    pub fn post(&self) -> Option<&Post<'r>> {
+        self.post.as_ref()
+    }
+
+    pub fn post_mut(&mut self) -> Option<&mut Post<'r>> {
+        self.post.as_mut()
+    }
+
+    /// Why the post could not be loaded after it was submitted,
+    /// None if it was loaded or has not been tried.
+    pub fn load_error(&self) -> Option<&io::Error> {
+        self.load_error.as_ref()
+    }
+
+    /// Loads the post if it has not been loaded already.
+    pub async fn load(&mut self) -> io::Result<&mut Post<'r>> {
+        let post = match self.post.take() {
+            Some(post) => post,
+            None => self.reddit.post(&self.fullname).await?,
+        };
+        self.load_error = None;
+        Ok(self.post.get_or_insert(post))
+    }
+
+    /// The post, loading it first if needed.
+    pub async fn into_post(mut self) -> io::Result<Post<'r>> {
+        self.load().await?;
+        self.post
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Post was not loaded."))
+    }
+
+    /// Number of drafts the user has saved.
+    pub fn drafts_count(&self) -> Option<i32> {
+        self.drafts_count
+    }
+
+    /// Any warnings reddit sent with the response.
+    pub fn warnings(&self) -> &[Value] {
+        &self.warnings
+    }
+}
+
 pub struct SubredditLink<'r> {
     pub reddit: &'r Reddit,
     pub subreddit: String,
@@ -41,7 +335,7 @@ pub struct SubredditLink<'r> {
 impl<'r> SubredditLink<'r> {
     pub fn new(reddit: &'r Reddit, subreddit: &str) -> SubredditLink<'r> {
         SubredditLink {
-            reddit: reddit,
+            reddit,
             subreddit: subreddit.to_owned(),
         }
     }
@@ -70,6 +364,59 @@ impl<'r> SubredditLink<'r> {
         Ok(ContentStream::new(self.reddit.clone(), ep))
     }
 
+    fn mod_queue_ep(&self, queue: ModQueue, only: Option<QueueOnly>) -> io::Result<Endpoint> {
+        let ep = self.reddit.ep(queue.endpoint().subreddit(self.name()))?;
+        Ok(match only {
+            Some(only) => ep.add_query_pairs(&[("only", only.to_str())]),
+            None => ep,
+        })
+    }
+
+    /// First page of a moderation queue, newest first.
+    /// Items are posts and comments, see [ModerateData::mod_reports](crate::models::ModerateData::mod_reports)
+    /// and [ModerateData::user_reports](crate::models::ModerateData::user_reports) for why they were reported.
+    pub async fn mod_queue(&self, queue: ModQueue, only: Option<QueueOnly>) -> io::Result<Listing<'r, Thing<'r>>> {
+        let ep = self
+            .mod_queue_ep(queue, only)?
+            .add_query_pairs(&[("limit", "100")]);
+        Listing::new_listing(self.reddit, ep).await
+    }
+
+    /// Stream of new items in a moderation queue.
+    ///
+    /// Items are compared by their fullname, reports and approval time (see
+    /// [ThingData::feed_key](crate::feed::Feedable::feed_key)), so an item already in
+    /// the queue is sent again when it gets new reports or is reported again after being approved.
+    pub fn mod_queue_stream(&self, queue: ModQueue, only: Option<QueueOnly>) -> io::Result<ContentStream<ThingData>> {
+        let ep = self.mod_queue_ep(queue, only)?;
+        Ok(ContentStream::new(self.reddit.clone(), ep))
+    }
+
+    /// Ban, mute and approve users.
+    pub fn relationships(&self) -> Relationships<'_, 'r> {
+        Relationships::new(self)
+    }
+
+    /// First page of the mod log, newest first.
+    pub async fn mod_log(&self, options: &ModLogOptions) -> io::Result<Listing<'r, ModLogEntry<'r>>> {
+        let ep = self.reddit.ep(endpoints::ABOUT_LOG.subreddit(self.name()))?;
+        let ep = options.apply(ep).add_query_pairs(&[("limit", "100")]);
+        Listing::new_listing(self.reddit, ep).await
+    }
+
+    /// Stream of new entries in the mod log.
+    pub fn mod_log_stream(&self, options: &ModLogOptions) -> io::Result<ContentStream<ModAction>> {
+        let ep = self.reddit.ep(endpoints::ABOUT_LOG.subreddit(self.name()))?;
+        Ok(ContentStream::new(self.reddit.clone(), options.apply(ep)))
+    }
+
+    /// The subreddits rules and reddits site rules.
+    /// Use [SubredditRules::report_reasons] to get the reasons things can be reported for.
+    pub async fn rules(&self) -> io::Result<SubredditRules> {
+        let ep = self.reddit.ep(endpoints::RULES.subreddit(&self.subreddit))?;
+        self.reddit.api.get_api::<SubredditRules>(ep.to_url()).await
+    }
+
     pub async fn top(&self) -> io::Result<Vec<Post<'r>>> {
         let ep = self
             .reddit
@@ -89,27 +436,145 @@ impl<'r> SubredditLink<'r> {
     }
 
 
-    pub async fn submit(&self, title: &str, submission: SubredditSubmission<'_>) -> io::Result<SubredditSubmitResponse> {
-        let submit = SubredditSubmit {
-            kind: submission.kind(),
-            sr: self.name(),
-            title: title,
-            resubmit: true,
-            iden: None,
-            captcha: None,
-        };
+    /// The subreddits removal reasons, in the order moderators have set.
+    pub async fn removal_reasons(&self) -> io::Result<Vec<RemovalReason>> {
+        let ep = self.reddit.ep(endpoints::REMOVAL_REASONS_LIST.subreddit(self.name()))?;
+        Ok(self.reddit.api.get_api::<RemovalReasons>(ep.to_url()).await?.into_ordered())
+    }
+
+    /// Adds a removal reason and returns its id.
+    pub async fn add_removal_reason(&self, title: &str, message: &str) -> io::Result<String> {
+        let ep = self.reddit.ep(endpoints::REMOVAL_REASONS_LIST.subreddit(self.name()))?;
+        let created = self.reddit.api
+            .post_form_api::<RemovalReasonCreated, _>(ep.to_url(), &SendRemovalReason { title, message })
+            .await?;
+        Ok(created.id)
+    }
+
+    pub async fn update_removal_reason(&self, id: &str, title: &str, message: &str) -> io::Result<()> {
+        let ep = self.reddit.ep(endpoints::REMOVAL_REASON.subreddit(self.name()).id(id))?;
+        self.reddit.api
+            .form_action(Method::PUT, ep.to_url(), &SendRemovalReason { title, message })
+            .await
+    }
+
+    pub async fn delete_removal_reason(&self, id: &str) -> io::Result<()> {
+        let ep = self.reddit.ep(endpoints::REMOVAL_REASON.subreddit(self.name()).id(id))?;
+        self.reddit.api.delete_api(ep.to_url()).await
+    }
+
+    /// What a post has to meet to be accepted by the subreddit.
+    pub async fn post_requirements(&self) -> io::Result<PostRequirements> {
+        let ep = self.reddit.ep(endpoints::POST_REQUIREMENTS.subreddit(self.name()))?;
+        self.reddit.api.get_api::<PostRequirements>(ep.to_url()).await
+    }
+
+    /// Checks a post against the subreddits [PostRequirements] without submitting it.
+    /// Returns every requirement the post does not meet.
+    pub async fn check_submission(&self, title: &str, submission: &SubredditSubmission<'_>, options: &SubmitOptions) -> io::Result<Vec<PostRequirementViolation>> {
+        let requirements = self.post_requirements().await?;
+        let mut violations = requirements.check(title, submission, options);
+
+        if let (Some(days), SubredditSubmission::Link(link)) = (requirements.link_repost_age, submission) {
+            let ep = self.reddit.ep(endpoints::INFO)?.add_query_pairs(&[("url", link)]);
+            let oldest = Utc::now() - Duration::days(i64::from(days));
+            let reposts = self.reddit.get_list::<PostInfo>(ep).await?
+                .into_iter()
+                .filter(|p| p.subreddit.eq_ignore_ascii_case(self.name()) && p.created_utc > oldest)
+                .map(|p| PostRequirementViolation::Repost(p.moderate_data.name));
+            violations.extend(reposts);
+        }
+
+        Ok(violations)
+    }
+
+    /// Submits a new post and loads it.
+    pub async fn submit(&self, title: &str, submission: SubredditSubmission<'_>) -> io::Result<SubmittedPost<'r>> {
+        self.submit_with(title, submission, &SubmitOptions::default()).await
+    }
+
+    pub async fn submit_with(&self, title: &str, submission: SubredditSubmission<'_>, options: &SubmitOptions) -> io::Result<SubmittedPost<'r>> {
+        let resp = self.send_submission(title, submission, options).await?;
+
+        // The post was created even if it can not be loaded yet,
+        // so the error is kept and the post can be loaded later.
+        let mut submitted = SubmittedPost::new(self.reddit, resp);
+        if let Err(e) = submitted.load().await {
+            submitted.load_error = Some(e);
+        }
+        Ok(submitted)
+    }
+
+    /// Submits a post and returns reddits response without loading the post.
+    pub async fn send_submission(&self, title: &str, submission: SubredditSubmission<'_>, options: &SubmitOptions) -> io::Result<SubredditSubmitResponse> {
+        submission.validate()?;
+        if options.check_requirements {
+            let violations = self.check_submission(title, &submission, options).await?;
+            if !violations.is_empty() {
+                Err(SnooError::PostRequirements(violations))?;
+            }
+        }
+
+        let submit = options.to_submit(submission.kind(), self.name(), title);
        
-        let target_url = self.reddit.ep(endpoints::SUBMIT.subreddit(&self.name()))?;
+        let target_url = self.reddit.ep(endpoints::SUBMIT.subreddit(self.name()))?;
        
         match submission {
             SubredditSubmission::Link(link) => self.reddit.post_data::<_, SubredditSubmitResponse>(target_url,  &SubredditSubmitLink {
-                submit: submit,
+                submit,
                 url: link
             }).await,
             SubredditSubmission::Text(body) =>  self.reddit.post_data::<_, SubredditSubmitResponse>(target_url, &SubredditSubmitText {
-                submit: submit,
+                submit,
                 text: body   
-            }).await
+            }).await,
+            SubredditSubmission::Crosspost(fullname) => self.reddit.post_data::<_, SubredditSubmitResponse>(target_url, &SubredditSubmitCrosspost {
+                submit,
+                crosspost_fullname: fullname
+            }).await,
+            SubredditSubmission::Image(image) => {
+                let image = self.reddit.upload_media(image).await?;
+                self.reddit.post_data::<_, SubredditSubmitResponse>(target_url, &SubredditSubmitMedia {
+                    submit,
+                    url: &image.url,
+                    video_poster_url: None,
+                }).await
+            }
+            SubredditSubmission::Video { video, thumbnail, .. } => {
+                let video = self.reddit.upload_media(video).await?;
+                let thumbnail = self.reddit.upload_media(thumbnail).await?;
+                self.reddit.post_data::<_, SubredditSubmitResponse>(target_url, &SubredditSubmitMedia {
+                    submit,
+                    url: &video.url,
+                    video_poster_url: Some(&thumbnail.url),
+                }).await
+            }
+            SubredditSubmission::Gallery(items) => {
+                let mut uploaded = Vec::with_capacity(items.len());
+                for item in items {
+                    uploaded.push(self.reddit.upload_media(&item.media).await?);
+                }
+
+                let target_url = self.reddit.ep(endpoints::SUBMIT_GALLERY_POST)?;
+                self.reddit.post_json::<_, SubredditSubmitResponse>(target_url, &SubmitGalleryPost {
+                    submit,
+                    items: items.iter().zip(&uploaded).map(|(item, media)| SendGalleryItem {
+                        media_id: &media.asset_id,
+                        caption: item.caption.as_deref().unwrap_or(""),
+                        outbound_url: item.outbound_url.as_deref().unwrap_or(""),
+                    }).collect(),
+                    show_error_list: true,
+                }).await
+            }
+            SubredditSubmission::Poll { text, options, duration } => {
+                let target_url = self.reddit.ep(endpoints::SUBMIT_POLL_POST)?;
+                self.reddit.post_json::<_, SubredditSubmitResponse>(target_url, &SubmitPollPost {
+                    submit,
+                    text,
+                    options,
+                    duration,
+                }).await
+            }
         }
     }
 
@@ -156,17 +621,21 @@ impl<'r> Subreddit<'r> {
     }
 
     pub fn created(&self) -> Option<DateTime<Utc>> {
-        if let Some(created) = self.info.created {
-            let naive_datetime = NaiveDateTime::from_timestamp(created as i64, 0);
-            Some(DateTime::from_utc(naive_datetime, Utc))
-        } else {
-            None
-        }
+        self.info.created
+    }
+
+    /// Same as `created`, read from the `created_utc` field.
+    pub fn created_utc(&self) -> Option<DateTime<Utc>> {
+        self.info.created_utc
     }
 
-    pub async fn submit_text(&self, title: &str, body: &str) -> io::Result<SubredditSubmitResponse> {
+    pub async fn submit_text(&self, title: &str, body: &str) -> io::Result<SubmittedPost<'r>> {
         self.link.submit(title, SubredditSubmission::Text(body)).await
     }
+
+    pub async fn submit_with(&self, title: &str, submission: SubredditSubmission<'_>, options: &SubmitOptions) -> io::Result<SubmittedPost<'r>> {
+        self.link.submit_with(title, submission, options).await
+    }
 }
 
 impl<'r> AbstractedApi<'r> for Subreddit<'r> {
@@ -180,3 +649,84 @@ impl<'r> AbstractedApi<'r> for Subreddit<'r> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn submitted_post_from_response() {
+        let reddit = Reddit::new().unwrap();
+        let resp: SubredditSubmitResponse = serde_json::from_value(json!({
+            "url": "https://www.reddit.com/r/test/comments/gkxqzq/title/",
+            "id": "t3_gkxqzq",
+            "drafts_count": 2,
+            "warnings": null,
+        }))
+        .unwrap();
+
+        let submitted = SubmittedPost::new(&reddit, resp);
+        assert_eq!(submitted.fullname(), "t3_gkxqzq");
+        assert_eq!(submitted.url(), "https://www.reddit.com/r/test/comments/gkxqzq/title/");
+        assert!(submitted.post().is_none());
+        assert!(submitted.load_error().is_none());
+        assert_eq!(submitted.drafts_count(), Some(2));
+        assert!(submitted.warnings().is_empty());
+    }
+
+    fn poll(options: &[&str], duration: u32) -> io::Result<()> {
+        SubredditSubmission::Poll {
+            text: "Which is best?",
+            options,
+            duration,
+        }
+        .validate()
+    }
+
+    #[test]
+    fn poll_option_count() {
+        let options = ["a", "b", "c", "d", "e", "f", "g"];
+        for count in &[2, 6] {
+            assert!(poll(&options[..*count], 3).is_ok(), "{} options", count);
+        }
+        for count in &[0, 1, 7] {
+            let err = poll(&options[..*count], 3).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput, "{} options", count);
+        }
+    }
+
+    #[test]
+    fn poll_empty_options() {
+        assert!(poll(&["yes", ""], 3).is_err());
+        assert!(poll(&["yes", "  "], 3).is_err());
+    }
+
+    #[test]
+    fn poll_duration() {
+        for days in &[1, 7] {
+            assert!(poll(&["yes", "no"], *days).is_ok(), "{} days", days);
+        }
+        for days in &[0, 8] {
+            let err = poll(&["yes", "no"], *days).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput, "{} days", days);
+        }
+    }
+
+    #[test]
+    fn gallery_item_count() {
+        let image = MediaFile::new("cat.png", "image/png", b"png".to_vec());
+        let items = vec![GalleryItem::new(image); GALLERY_ITEMS_MAX + 1];
+
+        for count in &[GALLERY_ITEMS_MIN, GALLERY_ITEMS_MAX] {
+            let gallery = SubredditSubmission::Gallery(&items[..*count]);
+            assert!(gallery.validate().is_ok(), "{} items", count);
+        }
+        for count in &[0, GALLERY_ITEMS_MIN - 1, GALLERY_ITEMS_MAX + 1] {
+            let err = SubredditSubmission::Gallery(&items[..*count])
+                .validate()
+                .unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput, "{} items", count);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::items::{post::Post, submission::Comment, subreddit::Subreddit, AbstractedApi};
+use crate::models::{Fullname, ThingData};
+use crate::reddit::Reddit;
+
+/// An item that can be one of several types of reddit thing.
+pub enum Thing<'r> {
+    Post(Post<'r>),
+    Comment(Comment<'r>),
+    Subreddit(Subreddit<'r>),
+}
+
+impl<'r> Thing<'r> {
+    /// The fullname of the thing. e.g. `t3_gkxqzq`
+    pub fn name(&self) -> &str {
+        match self {
+            Thing::Post(p) => p.name(),
+            Thing::Comment(c) => c.name(),
+            Thing::Subreddit(s) => &s.info().name,
+        }
+    }
+
+    pub fn as_post(&self) -> Option<&Post<'r>> {
+        match self {
+            Thing::Post(p) => Some(p),
+            _ => None,
+        }
+    }
+
+    pub fn as_comment(&self) -> Option<&Comment<'r>> {
+        match self {
+            Thing::Comment(c) => Some(c),
+            _ => None,
+        }
+    }
+
+    pub fn as_subreddit(&self) -> Option<&Subreddit<'r>> {
+        match self {
+            Thing::Subreddit(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+/// Results of [Reddit::info]
+pub struct InfoResults<'r> {
+    /// The things that were found, in the order they were requested.
+    pub found: Vec<Thing<'r>>,
+    /// Fullnames reddit did not return anything for.
+    pub missing: Vec<Fullname>,
+    /// Fullnames of posts and comments that were deleted by their author.
+    /// These are still included in `found`.
+    /// Things removed by moderators are not included, see [ThingData::is_deleted].
+    pub deleted: Vec<Fullname>,
+}
+
+impl<'r> AbstractedApi<'r> for Thing<'r> {
+    type ApiType = ThingData;
+    type AbstractedType = Thing<'r>;
+
+    fn from_parent(reddit: &'r Reddit, info: Self::ApiType) -> Thing<'r> {
+        match info {
+            ThingData::Post(p) => Thing::Post(reddit.bind::<Post>(p)),
+            ThingData::Comment(c) => Thing::Comment(reddit.bind::<Comment>(c)),
+            ThingData::Subreddit(s) => Thing::Subreddit(reddit.bind::<Subreddit>(s)),
+        }
+    }
+}
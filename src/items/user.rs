@@ -1,8 +1,9 @@
 use crate::models::UserInfo;
 use crate::{endpoints, reddit::Reddit};
 
-use crate::items::{post::Post, submission::Comment, AbstractedApi};
+use crate::items::{listing::Listing, post::Post, submission::Comment, thing::Thing, AbstractedApi};
 
+use chrono::{DateTime, Utc};
 use std::io;
 
 /// Weak link to the user.
@@ -16,7 +17,7 @@ pub struct RedditUserLink<'r> {
 impl<'r> RedditUserLink<'r> {
     pub fn new(reddit: &'r Reddit, name: &str) -> RedditUserLink<'r> {
         RedditUserLink {
-            reddit: reddit,
+            reddit,
             username: name.to_owned(),
         }
     }
@@ -38,6 +39,20 @@ impl<'r> RedditUserLink<'r> {
         ))
     }
 
+    /// Posts and comments the user has saved, newest first.
+    /// Only visible to the user themselves, use [Listing::next] for older pages.
+    pub async fn saved(&self) -> io::Result<Listing<'r, Thing<'r>>> {
+        let ep = self.reddit.ep(endpoints::USER_SAVED.user(&self.username))?;
+        Listing::new_listing(self.reddit, ep).await
+    }
+
+    /// Posts the user has hidden, newest first.
+    /// Only visible to the user themselves, use [Listing::next] for older pages.
+    pub async fn hidden(&self) -> io::Result<Listing<'r, Post<'r>>> {
+        let ep = self.reddit.ep(endpoints::USER_HIDDEN.user(&self.username))?;
+        Listing::new_listing(self.reddit, ep).await
+    }
+
     pub async fn get(self) -> io::Result<RedditUser<'r>> {
         let ep = self.reddit.ep(endpoints::USER_ABOUT.user(&self.username))?;
         let about = self.reddit.get_data::<UserInfo>(ep).await?;
@@ -81,6 +96,16 @@ impl RedditUser<'_> {
         self.info.is_gold
     }
 
+    /// When the account was created.
+    pub fn created(&self) -> Option<DateTime<Utc>> {
+        self.info.created
+    }
+
+    /// Same as `created`, read from the `created_utc` field.
+    pub fn created_utc(&self) -> Option<DateTime<Utc>> {
+        self.info.created_utc
+    }
+
     pub async fn submitted(&'_ self) -> io::Result<Vec<Post<'_>>> {
         self.link.submitted().await
     }
@@ -88,6 +113,14 @@ impl RedditUser<'_> {
     pub async fn comments(&'_ self) -> io::Result<Vec<Comment<'_>>> {
         self.link.comments().await
     }
+
+    pub async fn saved(&'_ self) -> io::Result<Listing<'_, Thing<'_>>> {
+        self.link.saved().await
+    }
+
+    pub async fn hidden(&'_ self) -> io::Result<Listing<'_, Post<'_>>> {
+        self.link.hidden().await
+    }
 }
 
 impl<'r> AbstractedApi<'r> for RedditUser<'r> {
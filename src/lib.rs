@@ -1,15 +1,22 @@
 //! To get started, create a new [Reddit] instance.
 //! see the [/example](https://github.com/pigeonhands/snoo-rs/tree/master/examples) folder on the repo to for examples.
 pub mod endpoints;
+pub mod error;
 pub mod feed;
 pub mod items;
+pub mod link;
 pub mod models;
 pub mod rate_limit;
 pub mod reddit;
 pub mod reddit_api;
+#[cfg(test)]
+mod test_server;
 
-pub use endpoints::SearchSort;
+pub use endpoints::{CommentSort, ModQueue, QueueOnly, SearchSort};
+pub use error::SnooError;
 pub use items::*;
+pub use link::{LinkedItem, RedditLink};
+pub use models::Fullname;
 pub use rate_limit::RateLimiter;
 pub use reddit::Reddit;
 pub use reddit_api::{RedditApi, RedditApiAuthenticationUrl};
@@ -0,0 +1,377 @@
+//! Resolves the different forms of reddit links
+//! into a [RedditLink] that can be fetched.
+//!
+//! ```no_run
+//! # use snoo::{LinkedItem, Reddit};
+//! # async fn example() -> std::io::Result<()> {
+//! let r = Reddit::new()?;
+//!
+//! let link = r.resolve_link("https://redd.it/gkxqzq").await?;
+//! if let LinkedItem::Submission(s) = link.fetch(&r).await? {
+//!     println!("{}", s.op().title());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use crate::endpoints;
+use crate::items::{submission::Submission, subreddit::Subreddit, user::RedditUser};
+use crate::models::{MultiredditInfo, WikiPageInfo};
+use crate::reddit::Reddit;
+
+use reqwest::Url;
+use std::io;
+
+/// A parsed reddit link.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RedditLink {
+    /// `/comments/<id>`, `/r/<subreddit>/comments/<id>/<slug>` or `redd.it/<id>`
+    Submission { id: String },
+
+    /// A comment permalink inside of a submission.
+    /// `context` is the number of parent comments to load with it.
+    Comment {
+        submission_id: String,
+        comment_id: String,
+        context: Option<u32>,
+    },
+
+    /// `/r/<name>`
+    Subreddit { name: String },
+
+    /// `/u/<name>` or `/user/<name>`
+    User { name: String },
+
+    /// `/r/<subreddit>/wiki/<page>`
+    WikiPage { subreddit: String, page: String },
+
+    /// `/user/<user>/m/<name>`
+    Multireddit { user: String, name: String },
+}
+
+/// The item a [RedditLink] points to.
+pub enum LinkedItem<'r> {
+    Submission(Submission<'r>),
+    /// The submission loaded around the linked comment.
+    Comment {
+        submission: Submission<'r>,
+        comment_id: String,
+    },
+    Subreddit(Subreddit<'r>),
+    User(RedditUser<'r>),
+    WikiPage(WikiPageInfo),
+    Multireddit(MultiredditInfo),
+}
+
+fn bad_link(url: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("Not a recognised reddit link: {}", url),
+    )
+}
+
+/// Parses a link that may be missing the scheme or host.
+/// e.g. `reddit.com/r/rust`, `/r/rust` or `r/rust`
+fn parse_url(link: &str) -> io::Result<Url> {
+    let link = link.trim();
+    let full = if link.starts_with("http://") || link.starts_with("https://") {
+        link.to_owned()
+    } else if link.starts_with('/') {
+        format!("https://www.reddit.com{}", link)
+    } else if link.starts_with("r/") || link.starts_with("u/") || link.starts_with("user/") {
+        format!("https://www.reddit.com/{}", link)
+    } else {
+        format!("https://{}", link)
+    };
+
+    Url::parse(&full).map_err(|_| bad_link(link))
+}
+
+fn is_reddit_host(host: &str) -> bool {
+    host == "reddit.com"
+        || ["www.", "old.", "np.", "new.", "m.", "oauth."]
+            .iter()
+            .any(|p| host.strip_prefix(p) == Some("reddit.com"))
+}
+
+fn path_segments(url: &Url) -> Vec<&str> {
+    url.path()
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(|s| s.trim_end_matches(".json"))
+        .collect()
+}
+
+fn context_of(url: &Url) -> Option<u32> {
+    url.query_pairs()
+        .find(|(k, _)| k == "context")
+        .and_then(|(_, v)| v.parse().ok())
+}
+
+/// `comments/<id>[/<slug>[/<comment>]]`
+fn parse_comments(url: &Url, segments: &[&str]) -> Option<RedditLink> {
+    let id = segments.get(1)?;
+    Some(match segments.get(3) {
+        Some(comment_id) => RedditLink::Comment {
+            submission_id: (*id).to_owned(),
+            comment_id: (*comment_id).to_owned(),
+            context: context_of(url),
+        },
+        None => RedditLink::Submission {
+            id: (*id).to_owned(),
+        },
+    })
+}
+
+fn is_share_link(url: &Url) -> bool {
+    let segments = path_segments(url);
+    segments.len() >= 4 && segments[0] == "r" && segments[2] == "s"
+}
+
+/// Returns the full url of `link` if it is a share link
+/// that has to be followed before it can be parsed.
+pub(crate) fn share_link_url(link: &str) -> io::Result<Option<Url>> {
+    let url = parse_url(link)?;
+    let is_reddit = url.host_str().map(is_reddit_host).unwrap_or(false);
+    Ok(if is_reddit && is_share_link(&url) {
+        Some(url)
+    } else {
+        None
+    })
+}
+
+impl RedditLink {
+    /// Parses any of the common forms of reddit link.
+    ///
+    /// Share links (`/r/<subreddit>/s/<id>`) can only be resolved
+    /// by following their redirect, use [Reddit::resolve_link] for those.
+    pub fn parse(link: &str) -> io::Result<RedditLink> {
+        let url = parse_url(link)?;
+        let host = url.host_str().ok_or_else(|| bad_link(link))?;
+
+        let segments = path_segments(&url);
+
+        if host == "redd.it" || host == "www.redd.it" {
+            let id = segments.first().ok_or_else(|| bad_link(link))?;
+            return Ok(RedditLink::Submission {
+                id: (*id).to_owned(),
+            });
+        }
+
+        if !is_reddit_host(host) {
+            return Err(bad_link(link));
+        }
+
+        let parsed = match segments.as_slice() {
+            ["comments", ..] => parse_comments(&url, &segments),
+            ["r", _, "s", _, ..] => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "Share links must be resolved with Reddit::resolve_link",
+                ))
+            }
+            ["r", _, "comments", ..] => parse_comments(&url, &segments[2..]),
+            ["r", subreddit, "wiki"] => Some(RedditLink::WikiPage {
+                subreddit: (*subreddit).to_owned(),
+                page: "index".to_owned(),
+            }),
+            ["r", subreddit, "wiki", page @ ..] => Some(RedditLink::WikiPage {
+                subreddit: (*subreddit).to_owned(),
+                page: page.join("/"),
+            }),
+            ["r", name, ..] => Some(RedditLink::Subreddit {
+                name: (*name).to_owned(),
+            }),
+            ["u", user, "m", name, ..] | ["user", user, "m", name, ..] => {
+                Some(RedditLink::Multireddit {
+                    user: (*user).to_owned(),
+                    name: (*name).to_owned(),
+                })
+            }
+            ["u", _, "comments", ..] | ["user", _, "comments", ..] => {
+                parse_comments(&url, &segments[2..])
+            }
+            ["u", name, ..] | ["user", name, ..] => Some(RedditLink::User {
+                name: (*name).to_owned(),
+            }),
+            _ => None,
+        };
+
+        parsed.ok_or_else(|| bad_link(link))
+    }
+
+    /// Fetches the item the link points to.
+    pub async fn fetch<'r>(&self, reddit: &'r Reddit) -> io::Result<LinkedItem<'r>> {
+        Ok(match self {
+            RedditLink::Submission { id } => LinkedItem::Submission(reddit.submission(id).await?),
+            RedditLink::Comment {
+                submission_id,
+                comment_id,
+                context,
+            } => LinkedItem::Comment {
+                submission: reddit
+                    .submission_comment(submission_id, comment_id, *context)
+                    .await?,
+                comment_id: comment_id.clone(),
+            },
+            RedditLink::Subreddit { name } => {
+                LinkedItem::Subreddit(reddit.subreddit(name).get().await?)
+            }
+            RedditLink::User { name } => LinkedItem::User(reddit.user(name).get().await?),
+            RedditLink::WikiPage { subreddit, page } => {
+                let ep = reddit.ep(endpoints::WIKI_PAGE.subreddit(subreddit).page(page))?;
+                LinkedItem::WikiPage(reddit.get_data::<WikiPageInfo>(ep).await?.data)
+            }
+            RedditLink::Multireddit { user, name } => {
+                let ep = reddit.ep(endpoints::MULTIREDDIT_API.user(user).multi(name))?;
+                LinkedItem::Multireddit(reddit.get_data::<MultiredditInfo>(ep).await?.data)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(id: &str) -> RedditLink {
+        RedditLink::Submission { id: id.to_owned() }
+    }
+
+    fn comment(submission_id: &str, comment_id: &str, context: Option<u32>) -> RedditLink {
+        RedditLink::Comment {
+            submission_id: submission_id.to_owned(),
+            comment_id: comment_id.to_owned(),
+            context,
+        }
+    }
+
+    fn subreddit(name: &str) -> RedditLink {
+        RedditLink::Subreddit {
+            name: name.to_owned(),
+        }
+    }
+
+    fn user(name: &str) -> RedditLink {
+        RedditLink::User {
+            name: name.to_owned(),
+        }
+    }
+
+    fn wiki(subreddit: &str, page: &str) -> RedditLink {
+        RedditLink::WikiPage {
+            subreddit: subreddit.to_owned(),
+            page: page.to_owned(),
+        }
+    }
+
+    fn multi(user: &str, name: &str) -> RedditLink {
+        RedditLink::Multireddit {
+            user: user.to_owned(),
+            name: name.to_owned(),
+        }
+    }
+
+    #[test]
+    fn parse_links() {
+        let cases = vec![
+            ("https://redd.it/gkxqzq", submission("gkxqzq")),
+            ("redd.it/gkxqzq", submission("gkxqzq")),
+            ("https://www.redd.it/gkxqzq", submission("gkxqzq")),
+            ("https://reddit.com/comments/gkxqzq", submission("gkxqzq")),
+            ("https://www.reddit.com/comments/gkxqzq/", submission("gkxqzq")),
+            ("/comments/gkxqzq", submission("gkxqzq")),
+            (
+                "https://www.reddit.com/r/rust/comments/gkxqzq/some_title/",
+                submission("gkxqzq"),
+            ),
+            (
+                "https://old.reddit.com/r/rust/comments/gkxqzq/some_title/",
+                submission("gkxqzq"),
+            ),
+            ("https://np.reddit.com/r/rust/comments/gkxqzq/", submission("gkxqzq")),
+            ("https://m.reddit.com/r/rust/comments/gkxqzq/x", submission("gkxqzq")),
+            (
+                "https://www.reddit.com/r/rust/comments/gkxqzq/some_title.json",
+                submission("gkxqzq"),
+            ),
+            (
+                "https://www.reddit.com/r/rust/comments/gkxqzq/some_title/fqxyz12/",
+                comment("gkxqzq", "fqxyz12", None),
+            ),
+            (
+                "https://www.reddit.com/r/rust/comments/gkxqzq/some_title/fqxyz12/?context=3",
+                comment("gkxqzq", "fqxyz12", Some(3)),
+            ),
+            (
+                "https://old.reddit.com/r/rust/comments/gkxqzq/_/fqxyz12?utm_source=share&context=10",
+                comment("gkxqzq", "fqxyz12", Some(10)),
+            ),
+            (
+                "https://www.reddit.com/user/someone/comments/gkxqzq/title/",
+                submission("gkxqzq"),
+            ),
+            ("https://www.reddit.com/r/rust", subreddit("rust")),
+            ("https://old.reddit.com/r/rust/new/", subreddit("rust")),
+            ("reddit.com/r/rust", subreddit("rust")),
+            ("/r/rust", subreddit("rust")),
+            ("r/rust", subreddit("rust")),
+            ("https://www.reddit.com/u/someone", user("someone")),
+            ("https://www.reddit.com/user/someone/", user("someone")),
+            ("https://np.reddit.com/user/someone/submitted", user("someone")),
+            ("u/someone", user("someone")),
+            ("user/someone", user("someone")),
+            ("/u/someone", user("someone")),
+            ("https://www.reddit.com/user/someone/m/programming", multi("someone", "programming")),
+            ("https://old.reddit.com/u/someone/m/programming/new", multi("someone", "programming")),
+            ("https://www.reddit.com/r/rust/wiki", wiki("rust", "index")),
+            ("https://www.reddit.com/r/rust/wiki/", wiki("rust", "index")),
+            ("https://www.reddit.com/r/rust/wiki/faq", wiki("rust", "faq")),
+            ("https://www.reddit.com/r/rust/wiki/config/sidebar", wiki("rust", "config/sidebar")),
+        ];
+
+        for (link, expected) in cases {
+            let parsed = RedditLink::parse(link).unwrap_or_else(|e| panic!("{}: {}", link, e));
+            assert_eq!(parsed, expected, "{}", link);
+        }
+    }
+
+    #[test]
+    fn parse_rejects_unsupported_links() {
+        let cases = [
+            "https://example.com/r/rust",
+            "https://notreddit.com/r/rust/comments/gkxqzq",
+            "https://reddit.com.example.com/r/rust",
+            "https://www.reddit.com/",
+            "https://www.reddit.com/comments/",
+            "https://redd.it/",
+            "https://www.reddit.com/r/rust/s/aBcDeF123",
+        ];
+
+        for link in cases.iter() {
+            let err = RedditLink::parse(link).expect_err(link);
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput, "{}", link);
+        }
+    }
+
+    #[test]
+    fn share_links() {
+        let url = share_link_url("https://www.reddit.com/r/rust/s/aBcDeF123").unwrap();
+        assert_eq!(
+            url.map(|u| u.to_string()),
+            Some("https://www.reddit.com/r/rust/s/aBcDeF123".to_owned())
+        );
+
+        let url = share_link_url("reddit.com/r/rust/s/aBcDeF123").unwrap();
+        assert!(url.is_some());
+
+        assert!(
+            share_link_url("https://www.reddit.com/r/rust/comments/gkxqzq/")
+                .unwrap()
+                .is_none()
+        );
+        assert!(share_link_url("https://example.com/r/rust/s/aBcDeF123")
+            .unwrap()
+            .is_none());
+        assert!(share_link_url("https://redd.it/gkxqzq").unwrap().is_none());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::models::timestamp;
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug)]
@@ -14,8 +16,10 @@ pub struct AuthResponse {
 pub struct OAuthMeResponse {
     pub comment_karma: i32,
     pub link_karma: i32,
-    pub created: f64,
-    pub created_utc: f64,
+    #[serde(with = "timestamp")]
+    pub created: DateTime<Utc>,
+    #[serde(with = "timestamp")]
+    pub created_utc: DateTime<Utc>,
     pub has_mail: bool,
     pub has_mod_mail: bool,
     pub has_verified_email: bool,
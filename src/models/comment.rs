@@ -1,5 +1,10 @@
-use crate::models::{ModerateData, VoteData};
+use crate::models::{
+    nullable, timestamp, ModerateData, RedditResponseGeneric, TaggedListing, VoteData,
+};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 
 
 #[derive(Serialize)]
@@ -8,11 +13,55 @@ pub struct SendComment<'a> {
     pub text : &'a str,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+/// A placeholder for comments that were not loaded.
+/// They can be loaded with `api/morechildren`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MoreComments {
+    pub count: i32,
+    pub name: String,
+    pub id: String,
+    pub parent_id: String,
+    pub depth: i32,
+    /// Ids of the comments that were not loaded, without the `t1_` prefix.
+    pub children: Vec<String>,
+}
+
+impl MoreComments {
+    /// "Continue this thread" links have no children.
+    /// The rest of the thread has to be loaded from the parent comment.
+    pub fn is_continue_thread(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+/// A child in a comment tree.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "kind", content = "data")]
+pub enum CommentNode {
+    #[serde(rename = "t1")]
+    Comment(Box<CommentData>),
+
+    #[serde(rename = "more")]
+    More(MoreComments),
+}
+
+impl CommentNode {
+    pub fn parent_id(&self) -> &str {
+        match self {
+            CommentNode::Comment(c) => &c.parent_id,
+            CommentNode::More(m) => &m.parent_id,
+        }
+    }
+}
+
+pub type CommentListing = TaggedListing<CommentNode>;
+
+/// Reddit sends `""` when a comment has no replies.
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum CommentReplies {
     NoReply(String),
-    HasReplies(Vec<CommentData>),
+    HasReplies(RedditResponseGeneric<CommentListing>),
 }
 
 impl Default for CommentReplies {
@@ -21,7 +70,16 @@ impl Default for CommentReplies {
     }
 }
 
-#[derive(Deserialize, Debug, Clone)]
+impl CommentReplies {
+    pub fn into_nodes(self) -> Vec<CommentNode> {
+        match self {
+            CommentReplies::NoReply(_) => Vec::new(),
+            CommentReplies::HasReplies(listing) => listing.data.children,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct CommentData {
     #[serde(flatten)]
     pub vote_data: VoteData,
@@ -31,20 +89,31 @@ pub struct CommentData {
 
     pub author: String,
     pub body: String,
+    #[serde(default, deserialize_with = "nullable")]
     pub body_html: String,
     pub parent_id: String,
     pub subreddit: String,
     pub link_id: String,
     pub link_title: Option<String>,
 
+    #[serde(default)]
     pub replies: CommentReplies,
 
+    #[serde(default, deserialize_with = "nullable")]
     pub total_awards_received: i32,
-    pub approved_at_utc: Option<f32>,
+
+    #[serde(with = "timestamp")]
+    pub created: DateTime<Utc>,
+    #[serde(with = "timestamp")]
+    pub created_utc: DateTime<Utc>,
+
+    /// Any fields reddit sent that are not part of the model.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct CommentSubmitResponse {
     #[serde(flatten)]
     pub vote_data: VoteData,
@@ -54,14 +123,51 @@ pub struct CommentSubmitResponse {
 
     pub author: String,
     pub body: String,
+    #[serde(default, deserialize_with = "nullable")]
     pub body_html: String,
     pub parent_id: String,
     pub subreddit: String,
     pub link_id: String,
     pub link_title: Option<String>,
 
+    #[serde(default)]
     pub replies: CommentReplies,
 
+    #[serde(default, deserialize_with = "nullable")]
     pub total_awards_received: i32,
-    pub approved_at_utc: Option<f32>,
+
+    #[serde(with = "timestamp")]
+    pub created: DateTime<Utc>,
+    #[serde(with = "timestamp")]
+    pub created_utc: DateTime<Utc>,
+
+    /// Any fields reddit sent that are not part of the model.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures::comment_json;
+    use serde_json::json;
+
+    #[test]
+    fn submit_response_keeps_extra_fields() {
+        let raw = comment_json(
+            "abc",
+            "t3_link",
+            "t3_link",
+            json!({"gildings": {}, "collapsed_reason": null}),
+        );
+        let comment: CommentSubmitResponse = serde_json::from_value(raw["data"].clone()).unwrap();
+        assert_eq!(comment.moderate_data.name, "t1_abc");
+        assert_eq!(comment.extra.get("gildings"), Some(&json!({})));
+        assert_eq!(comment.extra.get("collapsed_reason"), Some(&json!(null)));
+        // Fields of the model and its flattened parts are not repeated in `extra`.
+        assert!(!comment.extra.contains_key("body"));
+        assert!(!comment.extra.contains_key("name"));
+        assert!(!comment.extra.contains_key("ups"));
+        assert!(!comment.extra.contains_key("stickied"));
+    }
 }
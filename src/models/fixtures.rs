@@ -0,0 +1,28 @@
+//! Raw json of reddit things shared by tests.
+use serde_json::{json, Value};
+
+/// A `t1` comment as it is sent in a listing. `id` is without the `t1_` prefix.
+/// Fields in `data` are added to the comment, replacing the defaults.
+pub(crate) fn comment_json(id: &str, parent_id: &str, link_id: &str, data: Value) -> Value {
+    let mut comment = json!({
+        "id": id,
+        "name": format!("t1_{}", id),
+        "parent_id": parent_id,
+        "link_id": link_id,
+        "author": "someone",
+        "body": format!("comment {}", id),
+        "subreddit": "rust",
+        "author_flair_css_class": null,
+        "author_flair_text": null,
+        "ups": 1,
+        "downs": 0,
+        "score": 1,
+        "likes": null,
+        "created": 1589500000.0,
+        "created_utc": 1589500000.0,
+    });
+    if let (Some(comment), Value::Object(data)) = (comment.as_object_mut(), data) {
+        comment.extend(data);
+    }
+    json!({"kind": "t1", "data": comment})
+}
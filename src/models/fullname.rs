@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::fmt;
+use std::io;
+
+/// The type prefix of a [Fullname]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThingKind {
+    Comment,
+    Account,
+    Link,
+    Message,
+    Subreddit,
+    Award,
+}
+
+impl ThingKind {
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            ThingKind::Comment => "t1",
+            ThingKind::Account => "t2",
+            ThingKind::Link => "t3",
+            ThingKind::Message => "t4",
+            ThingKind::Subreddit => "t5",
+            ThingKind::Award => "t6",
+        }
+    }
+
+    pub fn from_prefix(prefix: &str) -> Option<ThingKind> {
+        Some(match prefix {
+            "t1" => ThingKind::Comment,
+            "t2" => ThingKind::Account,
+            "t3" => ThingKind::Link,
+            "t4" => ThingKind::Message,
+            "t5" => ThingKind::Subreddit,
+            "t6" => ThingKind::Award,
+            _ => return None,
+        })
+    }
+}
+
+/// The full name of a reddit thing.
+/// The type prefix and id. e.g. `t3_gkxqzq`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Fullname(String);
+
+impl Fullname {
+    pub fn new(kind: ThingKind, id: &str) -> Fullname {
+        Fullname(format!("{}_{}", kind.prefix(), id))
+    }
+
+    /// Parses a fullname, checking it has a valid type prefix.
+    pub fn parse(name: &str) -> io::Result<Fullname> {
+        let valid = match name.split_once('_') {
+            Some((prefix, id)) => ThingKind::from_prefix(prefix).is_some() && !id.is_empty(),
+            None => false,
+        };
+
+        if valid {
+            Ok(Fullname(name.to_owned()))
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Invalid fullname: {}", name),
+            ))
+        }
+    }
+
+    pub fn kind(&self) -> ThingKind {
+        ThingKind::from_prefix(&self.0[..2]).unwrap()
+    }
+
+    /// The id without the type prefix.
+    pub fn id(&self) -> &str {
+        &self.0[3..]
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Fullname {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for Fullname {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for Fullname {
+    type Error = io::Error;
+
+    fn try_from(name: String) -> io::Result<Fullname> {
+        Fullname::parse(&name)
+    }
+}
+
+impl From<Fullname> for String {
+    fn from(name: Fullname) -> String {
+        name.0
+    }
+}
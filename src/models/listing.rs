@@ -1,11 +1,55 @@
-use crate::models::RedditResponseGeneric;
-use serde::Deserialize;
+use crate::feed::Feedable;
+use crate::models::{CommentData, ModerateData, PostInfo, RedditResponseGeneric, SubredditInfo};
+use serde::de::{DeserializeOwned, Deserializer};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-#[derive(Deserialize, Debug, Clone)]
+/// A type that can be read from the children of a listing.
+pub trait ListingChild: DeserializeOwned {
+    /// Parses the children of a listing, separating out the ones that fail.
+    /// By default children are `{"kind": ..., "data": T}`, types that
+    /// are tagged with the kind themselves e.g. [ThingData] override this.
+    fn from_children(children: Vec<Value>) -> (Vec<Self>, Vec<InvalidChild>) {
+        let (children, invalid) = parse_children::<RedditResponseGeneric<Self>>(children);
+        (children.into_iter().map(|c| c.data).collect(), invalid)
+    }
+}
+
+/// A listing child that could not be parsed.
+/// Kept so that one bad item does not fail the whole listing.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct InvalidChild {
+    pub error: String,
+    pub data: Value,
+}
+
+/// Parses each child on its own, separating out
+/// the ones that fail into [InvalidChild]s.
+pub(crate) fn parse_children<T: DeserializeOwned>(raw: Vec<Value>) -> (Vec<T>, Vec<InvalidChild>) {
+    let mut children = Vec::new();
+    let mut invalid = Vec::new();
+    for child in raw {
+        match T::deserialize(&child) {
+            Ok(c) => children.push(c),
+            Err(e) => invalid.push(InvalidChild {
+                error: e.to_string(),
+                data: child,
+            }),
+        }
+    }
+    (children, invalid)
+}
+
+#[derive(Serialize, Debug, Clone)]
 pub struct ListingData<T> {
     pub modhash: Option<String>,
     pub dist: Option<i32>,
     pub children: Vec<RedditResponseGeneric<T>>,
+
+    /// Children that failed to parse as `T`.
+    /// Serialized so they survive a round trip, reddit never sends this field.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub invalid_children: Vec<InvalidChild>,
 }
 
 impl<T> ListingData<T> {
@@ -17,3 +61,296 @@ impl<T> ListingData<T> {
         out
     }
 }
+
+#[derive(Deserialize)]
+struct RawListingData {
+    modhash: Option<String>,
+    dist: Option<i32>,
+    children: Vec<Value>,
+    #[serde(default)]
+    invalid_children: Vec<InvalidChild>,
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for ListingData<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = RawListingData::deserialize(deserializer)?;
+        let (children, mut invalid_children) = parse_children(raw.children);
+        invalid_children.extend(raw.invalid_children);
+        Ok(ListingData {
+            modhash: raw.modhash,
+            dist: raw.dist,
+            children,
+            invalid_children,
+        })
+    }
+}
+
+/// A thing that can be returned in a listing with mixed types.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "kind", content = "data")]
+pub enum ThingData {
+    #[serde(rename = "t1")]
+    Comment(CommentData),
+
+    #[serde(rename = "t3")]
+    Post(PostInfo),
+
+    #[serde(rename = "t5")]
+    Subreddit(SubredditInfo),
+}
+
+impl ThingData {
+    /// The fullname of the thing. e.g. `t3_gkxqzq`
+    pub fn name(&self) -> &str {
+        match self {
+            ThingData::Comment(c) => &c.moderate_data.name,
+            ThingData::Post(p) => &p.moderate_data.name,
+            ThingData::Subreddit(s) => &s.name,
+        }
+    }
+
+    fn moderate_data(&self) -> Option<&ModerateData> {
+        match self {
+            ThingData::Comment(c) => Some(&c.moderate_data),
+            ThingData::Post(p) => Some(&p.moderate_data),
+            ThingData::Subreddit(_) => None,
+        }
+    }
+
+    /// If the post or comment has been deleted by its author.
+    ///
+    /// Only the author being `[deleted]` is checked, posts and comments
+    /// removed by moderators or reddit keep their author and are not detected.
+    pub fn is_deleted(&self) -> bool {
+        match self {
+            ThingData::Comment(c) => c.author == "[deleted]",
+            ThingData::Post(p) => p.author == "[deleted]",
+            ThingData::Subreddit(_) => false,
+        }
+    }
+}
+
+impl ListingChild for ThingData {
+    fn from_children(children: Vec<Value>) -> (Vec<Self>, Vec<InvalidChild>) {
+        parse_children(children)
+    }
+}
+
+impl Feedable for ThingData {
+    fn feed_id(&self) -> String {
+        self.name().to_owned()
+    }
+
+    /// The fullname with the reports and when it was approved, so a thing in
+    /// a moderation queue is sent again when it gets new reports or is
+    /// reported again after being approved.
+    fn feed_key(&self) -> String {
+        let data = match self.moderate_data() {
+            Some(data) => data,
+            None => return self.feed_id(),
+        };
+
+        let mut reports: Vec<String> = data
+            .user_reports
+            .iter()
+            .map(|r| format!("{}x{}", r.count, r.reason.as_deref().unwrap_or("")))
+            .chain(
+                data.mod_reports
+                    .iter()
+                    .map(|r| format!("{}:{}", r.moderator, r.reason.as_deref().unwrap_or(""))),
+            )
+            .collect();
+        reports.sort();
+
+        format!(
+            "{}|{}|{}|{}",
+            self.name(),
+            data.num_reports.unwrap_or(0),
+            data.approved_at_utc.map(|t| t.timestamp()).unwrap_or(0),
+            reports.join(",")
+        )
+    }
+}
+
+/// A listing where the children can be of different types.
+/// `T` is an enum tagged with the childrens `kind`, e.g. [ThingData]
+#[derive(Serialize, Debug, Clone)]
+pub struct TaggedListing<T> {
+    pub modhash: Option<String>,
+    pub dist: Option<i32>,
+    pub children: Vec<T>,
+    pub after: Option<String>,
+    pub before: Option<String>,
+
+    /// Children that were not a known kind or failed to parse.
+    /// Serialized so they survive a round trip, reddit never sends this field.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub invalid_children: Vec<InvalidChild>,
+}
+
+/// A listing of posts, comments and subreddits.
+pub type ThingListing = TaggedListing<ThingData>;
+
+#[derive(Deserialize)]
+struct RawTaggedListing {
+    modhash: Option<String>,
+    dist: Option<i32>,
+    children: Vec<Value>,
+    after: Option<String>,
+    before: Option<String>,
+    #[serde(default)]
+    invalid_children: Vec<InvalidChild>,
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for TaggedListing<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = RawTaggedListing::deserialize(deserializer)?;
+        let (children, mut invalid_children) = parse_children(raw.children);
+        invalid_children.extend(raw.invalid_children);
+        Ok(TaggedListing {
+            modhash: raw.modhash,
+            dist: raw.dist,
+            children,
+            after: raw.after,
+            before: raw.before,
+            invalid_children,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures::comment_json;
+    use serde_json::json;
+
+    fn raw_listing() -> Value {
+        json!({
+            "modhash": null,
+            "dist": 2,
+            "after": null,
+            "before": null,
+            "children": [
+                {"kind": "t9", "data": {"name": "t9_unknown"}},
+                {"kind": "t1", "data": "not a comment"}
+            ]
+        })
+    }
+
+    #[test]
+    fn tagged_listing_keeps_invalid_children() {
+        let listing: ThingListing = serde_json::from_value(raw_listing()).unwrap();
+        assert!(listing.children.is_empty());
+        assert_eq!(listing.invalid_children.len(), 2);
+
+        let json = serde_json::to_value(&listing).unwrap();
+        let listing: ThingListing = serde_json::from_value(json).unwrap();
+        assert_eq!(listing.invalid_children.len(), 2);
+        assert_eq!(listing.invalid_children[0].data["kind"], "t9");
+    }
+
+    #[test]
+    fn listing_data_keeps_invalid_children() {
+        let listing: ListingData<i32> = serde_json::from_value(raw_listing()).unwrap();
+        assert_eq!(listing.invalid_children.len(), 2);
+
+        let json = serde_json::to_value(&listing).unwrap();
+        let listing: ListingData<i32> = serde_json::from_value(json).unwrap();
+        assert_eq!(listing.invalid_children.len(), 2);
+        assert_eq!(listing.invalid_children[1].data["data"], "not a comment");
+    }
+
+    #[test]
+    fn empty_invalid_children_not_serialized() {
+        let listing: ThingListing = serde_json::from_value(json!({
+            "modhash": null, "dist": 0, "after": null, "before": null, "children": []
+        }))
+        .unwrap();
+        let json = serde_json::to_value(&listing).unwrap();
+        assert!(json.get("invalid_children").is_none());
+    }
+
+    #[derive(Deserialize, Debug)]
+    struct Named {
+        name: String,
+    }
+
+    impl ListingChild for Named {}
+
+    #[test]
+    fn from_children_keeps_invalid_children() {
+        let (children, invalid) = Named::from_children(vec![
+            json!({"kind": "t3", "data": {"name": "t3_first"}}),
+            json!({"kind": "t3", "data": {"title": "no name"}}),
+            json!({"kind": "t3"}),
+            json!({"kind": "t3", "data": {"name": "t3_second"}}),
+        ]);
+        let names: Vec<&str> = children.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["t3_first", "t3_second"]);
+        assert_eq!(invalid.len(), 2);
+        assert_eq!(invalid[0].data["data"]["title"], "no name");
+        assert_eq!(invalid[1].data, json!({"kind": "t3"}));
+    }
+
+    #[test]
+    fn thing_from_children_keeps_invalid_children() {
+        let (children, invalid) =
+            ThingData::from_children(raw_listing()["children"].as_array().unwrap().clone());
+        assert!(children.is_empty());
+        assert_eq!(invalid.len(), 2);
+        assert_eq!(invalid[0].data["kind"], "t9");
+    }
+
+    fn reported_comment(user_reports: Value, mod_reports: Value, approved_at: Value) -> ThingData {
+        serde_json::from_value(comment_json(
+            "abc",
+            "t3_link",
+            "t3_link",
+            json!({
+                "num_reports": user_reports.as_array().map(|r| r.len()).unwrap_or(0),
+                "user_reports": user_reports,
+                "mod_reports": mod_reports,
+                "approved_at_utc": approved_at,
+            }),
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn feed_key_changes_with_reports() {
+        let first = reported_comment(json!([["spam", 1, false, false]]), json!([]), Value::Null);
+        let same = reported_comment(json!([["spam", 1, false, false]]), json!([]), Value::Null);
+        assert_eq!(first.feed_id(), "t1_abc");
+        assert_eq!(first.feed_key(), same.feed_key());
+
+        let more_reports =
+            reported_comment(json!([["spam", 2, false, false]]), json!([]), Value::Null);
+        assert_eq!(more_reports.feed_id(), first.feed_id());
+        assert_ne!(more_reports.feed_key(), first.feed_key());
+
+        let new_reason = reported_comment(
+            json!([["spam", 1, false, false], ["rude", 1, false, false]]),
+            json!([]),
+            Value::Null,
+        );
+        assert_ne!(new_reason.feed_key(), first.feed_key());
+
+        let mod_report = reported_comment(
+            json!([["spam", 1, false, false]]),
+            json!([["breaks rule 1", "a_mod"]]),
+            Value::Null,
+        );
+        assert_ne!(mod_report.feed_key(), first.feed_key());
+    }
+
+    #[test]
+    fn feed_key_changes_after_approval() {
+        let reported = reported_comment(json!([["spam", 1, false, false]]), json!([]), Value::Null);
+        let re_reported = reported_comment(
+            json!([["spam", 1, false, false]]),
+            json!([]),
+            json!(1589600000.0),
+        );
+        assert_ne!(reported.feed_key(), re_reported.feed_key());
+    }
+}
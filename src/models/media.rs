@@ -0,0 +1,96 @@
+use crate::models::SubredditSubmit;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Min and max number of items in a gallery post.
+pub const GALLERY_ITEMS_MIN: usize = 2;
+pub const GALLERY_ITEMS_MAX: usize = 20;
+
+/// Sent to `api/media/asset` to request an upload lease.
+#[derive(Serialize)]
+pub struct SendMediaAsset<'a> {
+    pub filepath: &'a str,
+    pub mimetype: &'a str,
+}
+
+/// A form field that has to be sent with the upload.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MediaLeaseField {
+    pub name: String,
+    pub value: String,
+}
+
+/// Where and how to upload the file.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MediaLeaseArgs {
+    /// Url to upload to. Usually has no scheme. e.g. `//reddit-uploaded-media.s3-accelerate.amazonaws.com`
+    pub action: String,
+    pub fields: Vec<MediaLeaseField>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MediaAsset {
+    pub asset_id: String,
+    pub processing_state: Option<String>,
+    pub websocket_url: Option<String>,
+
+    /// Any fields reddit sent that are not part of the model.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// Response from `api/media/asset`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MediaAssetLease {
+    pub args: MediaLeaseArgs,
+    pub asset: MediaAsset,
+}
+
+impl MediaAssetLease {
+    /// The full url the file is uploaded to.
+    pub fn upload_url(&self) -> String {
+        if self.args.action.starts_with("//") {
+            format!("https:{}", self.args.action)
+        } else {
+            self.args.action.clone()
+        }
+    }
+
+    /// The value of one of the upload fields.
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.args
+            .fields
+            .iter()
+            .find(|f| f.name == name)
+            .map(|f| f.value.as_str())
+    }
+}
+
+/// Sent to `api/submit` for image and video posts.
+#[derive(Serialize)]
+pub struct SubredditSubmitMedia<'a> {
+    #[serde(flatten)]
+    pub submit: SubredditSubmit<'a>,
+    /// Url of the uploaded image or video.
+    pub url: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_poster_url: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+pub struct SendGalleryItem<'a> {
+    pub media_id: &'a str,
+    pub caption: &'a str,
+    pub outbound_url: &'a str,
+}
+
+/// Sent as json to `api/submit_gallery_post`
+#[derive(Serialize)]
+pub struct SubmitGalleryPost<'a> {
+    #[serde(flatten)]
+    pub submit: SubredditSubmit<'a>,
+    pub items: Vec<SendGalleryItem<'a>>,
+    pub show_error_list: bool,
+}
@@ -1,9 +1,12 @@
-use serde::Deserialize;
-#[derive(Deserialize, Debug, Clone)]
+use crate::models::{nullable, timestamp, ModReport, UserReport};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
 pub enum VoteEdited {
     IsEdited(bool),
-    TimeEdited(f32),
+    TimeEdited(#[serde(with = "timestamp")] DateTime<Utc>),
 }
 
 impl Default for VoteEdited {
@@ -12,25 +15,129 @@ impl Default for VoteEdited {
     }
 }
 
-#[derive(Deserialize, Debug, Clone, Default)]
+impl VoteEdited {
+    /// When the item was last edited.
+    pub fn time(&self) -> Option<DateTime<Utc>> {
+        match self {
+            VoteEdited::TimeEdited(time) => Some(*time),
+            VoteEdited::IsEdited(_) => None,
+        }
+    }
+}
+
+/// Which way to vote on a post or comment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VoteDirection {
+    Up,
+    Down,
+    Clear,
+}
+
+impl VoteDirection {
+    pub fn dir(&self) -> i32 {
+        match self {
+            VoteDirection::Up => 1,
+            VoteDirection::Down => -1,
+            VoteDirection::Clear => 0,
+        }
+    }
+
+    /// The `likes` value reddit shows for this vote
+    pub fn liked(&self) -> Option<bool> {
+        match self {
+            VoteDirection::Up => Some(true),
+            VoteDirection::Down => Some(false),
+            VoteDirection::Clear => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct SendVote<'a> {
+    pub id: &'a str,
+    pub dir: i32,
+}
+
+/// Form for apis that only take the fullname of a thing.
+/// e.g. `api/unsave`, `api/hide`
+#[derive(Serialize)]
+pub struct SendThingId<'a> {
+    pub id: &'a str,
+}
+
+#[derive(Serialize)]
+pub struct SendSave<'a> {
+    pub id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<&'a str>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SavedCategory {
+    pub category: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct SavedCategories {
+    pub categories: Vec<SavedCategory>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct VoteData {
     #[serde(rename = "likes")]
     pub liked: Option<bool>,
+    #[serde(default)]
     pub downs: i32,
+    #[serde(default)]
     pub ups: i32,
+    #[serde(default)]
     pub gilded: i32,
 }
 
-#[derive(Deserialize, Debug, Clone, Default)]
+impl VoteData {
+    /// Updates the vote and counts after voting.
+    pub fn apply_vote(&mut self, direction: VoteDirection) {
+        match self.liked {
+            Some(true) => self.ups -= 1,
+            Some(false) => self.downs -= 1,
+            None => {}
+        }
+        match direction {
+            VoteDirection::Up => self.ups += 1,
+            VoteDirection::Down => self.downs += 1,
+            VoteDirection::Clear => {}
+        }
+        self.liked = direction.liked();
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct ModerateData {
-    #[serde(rename = "stickied")]
+    #[serde(rename = "stickied", default)]
     pub is_stickied: bool,
 
     pub name: String,
     pub author_flair_css_class: Option<String>,
     pub author_flair_text: Option<String>,
+    #[serde(default)]
     pub edited: VoteEdited,
+    #[serde(default)]
     pub archived: bool,
+    #[serde(default)]
     pub saved: bool,
+    #[serde(default)]
     pub locked: bool,
+
+    #[serde(with = "timestamp::option", default)]
+    pub approved_at_utc: Option<DateTime<Utc>>,
+    #[serde(with = "timestamp::option", default)]
+    pub banned_at_utc: Option<DateTime<Utc>>,
+
+    /// Only visible to moderators.
+    #[serde(default, deserialize_with = "nullable")]
+    pub mod_reports: Vec<ModReport>,
+    /// Only visible to moderators.
+    #[serde(default, deserialize_with = "nullable")]
+    pub user_reports: Vec<UserReport>,
+    pub num_reports: Option<i32>,
 }
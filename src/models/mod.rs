@@ -1,28 +1,100 @@
 //! Models for the reddit json api
 pub mod auth;
 mod comment;
+#[cfg(test)]
+pub(crate) mod fixtures;
+mod fullname;
 mod listing;
+mod media;
 mod metadata;
+mod moderation;
+mod moderator;
+mod modlog;
+mod modmail;
+mod multireddit;
+mod poll;
 mod post;
+mod relationship;
+mod removal;
+mod report;
+mod requirements;
+mod rules;
 mod search;
 mod subreddit;
+pub mod timestamp;
 mod user;
+mod wiki;
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 pub use crate::models::{
-    comment::{CommentData, SendComment},
-    listing::ListingData,
-    metadata::{ModerateData, VoteData},
+    comment::{
+        CommentData, CommentListing, CommentNode, CommentReplies, CommentSubmitResponse,
+        MoreComments, SendComment,
+    },
+    fullname::{Fullname, ThingKind},
+    listing::{InvalidChild, ListingChild, ListingData, TaggedListing, ThingData, ThingListing},
+    media::{
+        MediaAsset, MediaAssetLease, MediaLeaseArgs, MediaLeaseField, SendGalleryItem,
+        SendMediaAsset, SubmitGalleryPost, SubredditSubmitMedia, GALLERY_ITEMS_MAX,
+        GALLERY_ITEMS_MIN,
+    },
+    metadata::{
+        ModerateData, SavedCategories, SavedCategory, SendSave, SendThingId, SendVote, VoteData,
+        VoteDirection,
+    },
+    moderation::{
+        DistinguishHow, SendDistinguish, SendOriginalContent, SendRemove, SendSticky,
+        SendSuggestedSort,
+    },
+    moderator::{ModPermission, ModPermissions, ModeratorInfo, SendSetPermissions},
+    modlog::{ModAction, ModActionType},
+    modmail::{
+        ModmailBulkRead, ModmailConversationInfo, ModmailConversationResponse,
+        ModmailConversations, ModmailMessage, ModmailMuteDuration, ModmailObjectId, ModmailOwner,
+        ModmailParticipant, ModmailSort, ModmailState, ModmailUnreadCount, SendModmailBulkRead, SendModmailCreate,
+        SendModmailMute, SendModmailRead, SendModmailReply,
+    },
+    multireddit::{MultiredditInfo, MultiredditSubreddit},
+    poll::{
+        PollData, PollOption, SubmitPollPost, POLL_DURATION_MAX, POLL_DURATION_MIN,
+        POLL_OPTIONS_MAX, POLL_OPTIONS_MIN,
+    },
     post::{PostImage, PostImages, PostInfo, PostPreview, PostSetFlair, PostEditText},
+    relationship::{
+        RelationshipInfo, RelationshipType, SendFriend, SendUnfriend, BAN_DURATION_MAX,
+    },
+    removal::{
+        render_template, ApplyRemovalReason, RemovalContext, RemovalMessageType, RemovalReason,
+        RemovalReasonCreated, RemovalReasons, SendJsonForm, SendRemovalMessage,
+        SendRemovalReason,
+    },
+    report::{ModReport, UserReport},
+    requirements::{
+        BodyRestrictionPolicy, GalleryRequirement, LinkRestrictionPolicy, PostRequirementViolation,
+        PostRequirements,
+    },
+    rules::{ReportReason, SendReport, SubredditRule, SubredditRules, REPORT_REASON_MAX},
     search::SearchInfo,
-    subreddit::{SubredditInfo, SubredditSubmit, SubredditSubmitLink, SubredditSubmitText, SubredditSubmitResponse},
+    subreddit::{SubredditInfo, SubredditSubmit, SubredditSubmitCrosspost, SubredditSubmitLink, SubredditSubmitText, SubredditSubmitResponse},
     user::UserInfo,
+    wiki::WikiPageInfo,
 };
 
 #[derive(Deserialize)]
 pub struct EmptyResponse();
 
+/// Deserializes a field that reddit sometimes sends as `null`
+/// into the types default value.
+/// Use with `#[serde(default, deserialize_with = "nullable")]`
+pub(crate) fn nullable<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
 
 #[derive(Deserialize, Clone, Debug)]
 pub struct RedditPostResponseJson<T> {
@@ -36,6 +108,13 @@ pub struct RedditPostResponse<T> {
 }
 
 
+/// Response from an api that dosent return data.
+/// Is just `{}` unless there was an error.
+#[derive(Deserialize, Clone, Debug)]
+pub struct RedditActionResponse {
+    pub json: Option<RedditPostResponseJson<serde_json::Value>>,
+}
+
 #[derive(Deserialize, Clone, Debug)]
 pub struct ThingsResponse<T> {
     pub things: Vec<T>,
@@ -68,7 +147,7 @@ pub struct RedditSetState<'a, T> {
 }
 
 
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct RedditResponseGeneric<T> {
     pub kind: String,
     pub data: T,
@@ -96,7 +175,7 @@ pub enum RedditResponse {
     Listing(ListingData<RedditResponse>),
 
     #[serde(rename = "modaction")]
-    ModAction(),
+    ModAction(ModAction),
 
     #[serde(rename = "more")]
     More(),
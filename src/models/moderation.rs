@@ -0,0 +1,63 @@
+use serde::Serialize;
+
+/// How a moderator's post or comment is distinguished.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DistinguishHow {
+    /// Mark as a moderator.
+    Moderator,
+    /// Remove the distinguish.
+    None,
+    /// Only available to admins.
+    Admin,
+    /// Only available to admins.
+    Special,
+}
+
+impl DistinguishHow {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            DistinguishHow::Moderator => "yes",
+            DistinguishHow::None => "no",
+            DistinguishHow::Admin => "admin",
+            DistinguishHow::Special => "special",
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct SendRemove<'a> {
+    pub id: &'a str,
+    pub spam: bool,
+}
+
+#[derive(Serialize)]
+pub struct SendDistinguish<'a> {
+    pub id: &'a str,
+    pub how: &'a str,
+    /// Pins a top level comment to the top of the thread.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sticky: Option<bool>,
+}
+
+#[derive(Serialize)]
+pub struct SendSticky<'a> {
+    pub id: &'a str,
+    pub state: bool,
+    /// Slot to sticky the post in, 1-4.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num: Option<u8>,
+}
+
+#[derive(Serialize)]
+pub struct SendSuggestedSort<'a> {
+    pub id: &'a str,
+    /// A comment sort or `"blank"` to clear it.
+    pub sort: &'a str,
+}
+
+#[derive(Serialize)]
+pub struct SendOriginalContent<'a> {
+    pub id: &'a str,
+    pub fullname: &'a str,
+    pub should_set_oc: bool,
+}
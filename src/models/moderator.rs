@@ -0,0 +1,169 @@
+use crate::models::timestamp;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Something a moderator is allowed to do in a subreddit.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ModPermission {
+    /// Every permission, including ones added later.
+    All,
+    /// Manage approved and banned users.
+    Access,
+    ChatConfig,
+    ChatOperator,
+    /// Manage settings, rules and the sidebar.
+    Config,
+    Flair,
+    /// Read and reply to modmail.
+    Mail,
+    /// Approve, remove and lock posts and comments.
+    Posts,
+    Wiki,
+    #[serde(other)]
+    Unknown,
+}
+
+impl ModPermission {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            ModPermission::All => "all",
+            ModPermission::Access => "access",
+            ModPermission::ChatConfig => "chat_config",
+            ModPermission::ChatOperator => "chat_operator",
+            ModPermission::Config => "config",
+            ModPermission::Flair => "flair",
+            ModPermission::Mail => "mail",
+            ModPermission::Posts => "posts",
+            ModPermission::Wiki => "wiki",
+            ModPermission::Unknown => "unknown",
+        }
+    }
+}
+
+/// The permissions of a moderator.
+///
+/// ```
+/// # use snoo::models::{ModPermission, ModPermissions};
+/// let perms = ModPermissions::none()
+///     .with(ModPermission::Posts)
+///     .with(ModPermission::Mail);
+/// assert_eq!(perms.to_param(), "-all,+posts,+mail");
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(transparent)]
+pub struct ModPermissions(Vec<ModPermission>);
+
+impl ModPermissions {
+    /// Full permissions.
+    pub fn all() -> Self {
+        ModPermissions(vec![ModPermission::All])
+    }
+
+    /// No permissions, add some with [ModPermissions::with].
+    pub fn none() -> Self {
+        ModPermissions(Vec::new())
+    }
+
+    pub fn with(mut self, permission: ModPermission) -> Self {
+        if !self.0.contains(&permission) {
+            self.0.push(permission);
+        }
+        self
+    }
+
+    pub fn is_all(&self) -> bool {
+        self.0.contains(&ModPermission::All)
+    }
+
+    /// If the moderator has `permission`, either directly or through [ModPermission::All]
+    pub fn has(&self, permission: ModPermission) -> bool {
+        self.is_all() || self.0.contains(&permission)
+    }
+
+    pub fn permissions(&self) -> &[ModPermission] {
+        &self.0
+    }
+
+    /// The form reddit expects, e.g. `-all,+posts,+mail`
+    pub fn to_param(&self) -> String {
+        if self.is_all() {
+            return "+all".to_owned();
+        }
+
+        let mut param = String::from("-all");
+        for permission in self.0.iter().filter(|p| **p != ModPermission::Unknown) {
+            param.push_str(",+");
+            param.push_str(permission.to_str());
+        }
+        param
+    }
+}
+
+/// A moderator of a subreddit, from `r/<subreddit>/about/moderators`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ModeratorInfo {
+    /// Username of the moderator.
+    pub name: String,
+    /// Fullname of the user. e.g. `t2_...`
+    pub id: String,
+    pub rel_id: Option<String>,
+    /// When they became a moderator.
+    #[serde(with = "timestamp")]
+    pub date: DateTime<Utc>,
+    #[serde(rename = "mod_permissions", default)]
+    pub permissions: ModPermissions,
+    pub author_flair_text: Option<String>,
+    pub author_flair_css_class: Option<String>,
+
+    /// Any fields reddit sent that are not part of the model.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+#[derive(Serialize)]
+pub struct SendSetPermissions<'a> {
+    pub name: &'a str,
+    #[serde(rename = "type")]
+    pub relationship: &'a str,
+    pub permissions: &'a str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn permissions_to_param() {
+        assert_eq!(ModPermissions::all().to_param(), "+all");
+        assert_eq!(ModPermissions::none().to_param(), "-all");
+
+        let perms = ModPermissions::none()
+            .with(ModPermission::Posts)
+            .with(ModPermission::Mail)
+            .with(ModPermission::Posts)
+            .with(ModPermission::ChatConfig);
+        assert_eq!(perms.to_param(), "-all,+posts,+mail,+chat_config");
+
+        // `all` wins over anything else that was added.
+        let perms = ModPermissions::none()
+            .with(ModPermission::Wiki)
+            .with(ModPermission::All);
+        assert_eq!(perms.to_param(), "+all");
+    }
+
+    #[test]
+    fn unknown_permissions_not_sent() {
+        let perms: ModPermissions =
+            serde_json::from_value(json!(["posts", "some_new_permission", "flair"])).unwrap();
+        assert!(!perms.is_all());
+        assert_eq!(perms.to_param(), "-all,+posts,+flair");
+
+        let perms: ModPermissions = serde_json::from_value(json!(["some_new_permission"])).unwrap();
+        assert_eq!(perms.to_param(), "-all");
+    }
+}
@@ -0,0 +1,239 @@
+use crate::feed::Feedable;
+use crate::models::{timestamp, ListingChild};
+
+use chrono::{DateTime, Utc};
+use serde::de::Deserializer;
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+macro_rules! mod_action_types {
+    ($($variant:ident => $name:expr),* $(,)?) => {
+        /// The type of a moderator action in the mod log.
+        /// Actions this crate does not know about are kept as [ModActionType::Other].
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum ModActionType {
+            $($variant,)*
+            Other(String),
+        }
+
+        impl ModActionType {
+            /// The name reddit uses for the action, e.g. `removelink`
+            pub fn to_str(&self) -> &str {
+                match self {
+                    $(ModActionType::$variant => $name,)*
+                    ModActionType::Other(name) => name,
+                }
+            }
+
+            pub fn from_name(name: &str) -> ModActionType {
+                match name {
+                    $($name => ModActionType::$variant,)*
+                    other => ModActionType::Other(other.to_owned()),
+                }
+            }
+        }
+    };
+}
+
+mod_action_types! {
+    BanUser => "banuser",
+    UnbanUser => "unbanuser",
+    SpamLink => "spamlink",
+    RemoveLink => "removelink",
+    ApproveLink => "approvelink",
+    SpamComment => "spamcomment",
+    RemoveComment => "removecomment",
+    ApproveComment => "approvecomment",
+    AddModerator => "addmoderator",
+    InviteModerator => "invitemoderator",
+    UninviteModerator => "uninvitemoderator",
+    AcceptModeratorInvite => "acceptmoderatorinvite",
+    RemoveModerator => "removemoderator",
+    SetPermissions => "setpermissions",
+    AddContributor => "addcontributor",
+    RemoveContributor => "removecontributor",
+    EditSettings => "editsettings",
+    EditFlair => "editflair",
+    Distinguish => "distinguish",
+    MarkNsfw => "marknsfw",
+    Spoiler => "spoiler",
+    Unspoiler => "unspoiler",
+    MarkOriginalContent => "markoriginalcontent",
+    WikiBanned => "wikibanned",
+    WikiUnbanned => "wikiunbanned",
+    WikiContributor => "wikicontributor",
+    RemoveWikiContributor => "removewikicontributor",
+    WikiRevise => "wikirevise",
+    WikiPermLevel => "wikipermlevel",
+    WikiPageListed => "wikipagelisted",
+    IgnoreReports => "ignorereports",
+    UnignoreReports => "unignorereports",
+    SetSuggestedSort => "setsuggestedsort",
+    Sticky => "sticky",
+    Unsticky => "unsticky",
+    SetContestMode => "setcontestmode",
+    UnsetContestMode => "unsetcontestmode",
+    Lock => "lock",
+    Unlock => "unlock",
+    MuteUser => "muteuser",
+    UnmuteUser => "unmuteuser",
+    CreateRule => "createrule",
+    EditRule => "editrule",
+    ReorderRules => "reorderrules",
+    DeleteRule => "deleterule",
+    AddRemovalReason => "addremovalreason",
+    CreateRemovalReason => "createremovalreason",
+    UpdateRemovalReason => "updateremovalreason",
+    DeleteRemovalReason => "deleteremovalreason",
+    EditPostRequirements => "edit_post_requirements",
+    ShowComment => "showcomment",
+    AddNote => "addnote",
+    DeleteNote => "deletenote",
+}
+
+impl Serialize for ModActionType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.to_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ModActionType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(ModActionType::from_name(&name))
+    }
+}
+
+/// An entry in a subreddits mod log.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ModAction {
+    /// e.g. `ModAction_8c3c6a52-...`
+    pub id: String,
+    pub action: ModActionType,
+    /// Username of the moderator that did the action.
+    #[serde(rename = "mod")]
+    pub moderator: String,
+    pub mod_id36: Option<String>,
+    pub subreddit: String,
+    pub sr_id36: Option<String>,
+
+    /// Fullname of the post, comment or user the action was done to.
+    pub target_fullname: Option<String>,
+    pub target_author: Option<String>,
+    pub target_title: Option<String>,
+    pub target_body: Option<String>,
+    pub target_permalink: Option<String>,
+
+    /// Short extra information, e.g. the ban duration.
+    pub details: Option<String>,
+    /// Longer extra information, e.g. the ban reason.
+    pub description: Option<String>,
+
+    #[serde(with = "timestamp")]
+    pub created_utc: DateTime<Utc>,
+
+    /// Any fields reddit sent that are not part of the model.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl ModAction {
+    /// If the action was done by reddit rather than a moderator of the subreddit.
+    pub fn is_admin_action(&self) -> bool {
+        self.moderator == "Anti-Evil Operations" || self.moderator == "reddit"
+    }
+}
+
+impl ListingChild for ModAction {}
+
+impl Feedable for ModAction {
+    fn feed_id(&self) -> String {
+        self.id.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn action_type_from_name() {
+        assert_eq!(ModActionType::from_name("banuser"), ModActionType::BanUser);
+        assert_eq!(
+            ModActionType::from_name("removelink"),
+            ModActionType::RemoveLink
+        );
+        assert_eq!(
+            ModActionType::from_name("edit_post_requirements"),
+            ModActionType::EditPostRequirements
+        );
+
+        for action in &[
+            ModActionType::ApproveComment,
+            ModActionType::WikiRevise,
+            ModActionType::DeleteNote,
+        ] {
+            assert_eq!(&ModActionType::from_name(action.to_str()), action);
+        }
+    }
+
+    #[test]
+    fn unknown_action_type_is_other() {
+        let action = ModActionType::from_name("some_new_action");
+        assert_eq!(action, ModActionType::Other("some_new_action".to_owned()));
+        assert_eq!(action.to_str(), "some_new_action");
+
+        // Names are case sensitive.
+        assert_eq!(
+            ModActionType::from_name("BanUser"),
+            ModActionType::Other("BanUser".to_owned())
+        );
+
+        let parsed: ModActionType = serde_json::from_value(json!("some_new_action")).unwrap();
+        assert_eq!(
+            serde_json::to_value(&parsed).unwrap(),
+            json!("some_new_action")
+        );
+    }
+
+    #[test]
+    fn parse_mod_action() {
+        // Trimmed from `r/<subreddit>/about/log`.
+        let action: ModAction = serde_json::from_value(json!({
+            "description": "Spam links to a blog",
+            "target_body": null,
+            "mod_id36": "5t8qd",
+            "created_utc": 1589587200.0,
+            "subreddit": "rust",
+            "target_title": null,
+            "target_permalink": null,
+            "subreddit_name_prefixed": "r/rust",
+            "details": "permanent",
+            "action": "banuser",
+            "target_author": "a_spammer",
+            "target_fullname": "t2_abc12",
+            "sr_id36": "2s7lj",
+            "id": "ModAction_8c3c6a52-96a1-11ea-a5f2-0e5a0ea1de7b",
+            "mod": "a_moderator"
+        }))
+        .unwrap();
+
+        assert_eq!(action.action, ModActionType::BanUser);
+        assert_eq!(action.moderator, "a_moderator");
+        assert_eq!(action.details.as_deref(), Some("permanent"));
+        assert_eq!(action.created_utc.timestamp(), 1589587200);
+        assert_eq!(
+            action.feed_id(),
+            "ModAction_8c3c6a52-96a1-11ea-a5f2-0e5a0ea1de7b"
+        );
+        assert_eq!(action.extra["subreddit_name_prefixed"], "r/rust");
+        assert!(!action.is_admin_action());
+
+        let value = serde_json::to_value(&action).unwrap();
+        assert_eq!(value["mod"], "a_moderator");
+        assert_eq!(value["action"], "banuser");
+    }
+}
@@ -0,0 +1,423 @@
+//! Models for new modmail, `api/mod/conversations`
+//!
+//! Unlike the rest of the api these use camelCase and ISO 8601 dates.
+use crate::models::nullable;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The folders modmail conversations are sorted into.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ModmailState {
+    New,
+    InProgress,
+    Archived,
+    Highlighted,
+    /// Conversations between moderators.
+    Mod,
+    Notifications,
+    JoinRequests,
+    Appeals,
+    All,
+}
+
+impl ModmailState {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            ModmailState::New => "new",
+            ModmailState::InProgress => "inprogress",
+            ModmailState::Archived => "archived",
+            ModmailState::Highlighted => "highlighted",
+            ModmailState::Mod => "mod",
+            ModmailState::Notifications => "notifications",
+            ModmailState::JoinRequests => "join_requests",
+            ModmailState::Appeals => "appeals",
+            ModmailState::All => "all",
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ModmailSort {
+    Recent,
+    /// Most recent moderator reply first.
+    Mod,
+    /// Most recent user reply first.
+    User,
+    Unread,
+}
+
+impl ModmailSort {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            ModmailSort::Recent => "recent",
+            ModmailSort::Mod => "mod",
+            ModmailSort::User => "user",
+            ModmailSort::Unread => "unread",
+        }
+    }
+}
+
+/// How long a user is muted from modmail.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ModmailMuteDuration {
+    Days3,
+    Days7,
+    Days28,
+}
+
+impl ModmailMuteDuration {
+    pub fn hours(&self) -> u32 {
+        match self {
+            ModmailMuteDuration::Days3 => 72,
+            ModmailMuteDuration::Days7 => 168,
+            ModmailMuteDuration::Days28 => 672,
+        }
+    }
+}
+
+/// A user or moderator in a modmail conversation.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailParticipant {
+    pub name: String,
+    #[serde(default)]
+    pub is_mod: bool,
+    #[serde(default)]
+    pub is_admin: bool,
+    /// If they started the conversation.
+    #[serde(default)]
+    pub is_op: bool,
+    #[serde(default)]
+    pub is_participant: bool,
+    /// If the moderator replied as the subreddit.
+    #[serde(default)]
+    pub is_hidden: bool,
+    #[serde(default)]
+    pub is_deleted: bool,
+
+    /// Any fields reddit sent that are not part of the model.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// The subreddit a conversation belongs to.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailOwner {
+    pub display_name: String,
+    /// Fullname of the subreddit. e.g. `t5_...`
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
+/// A reference to a message or mod action in a conversation.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ModmailObjectId {
+    pub id: String,
+    /// `messages` or `modActions`
+    pub key: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailConversationInfo {
+    pub id: String,
+    pub subject: String,
+    #[serde(default)]
+    pub is_auto: bool,
+    #[serde(default)]
+    pub is_highlighted: bool,
+    /// If the conversation is only between moderators.
+    #[serde(default)]
+    pub is_internal: bool,
+    #[serde(default)]
+    pub is_repliable: bool,
+    pub last_updated: DateTime<Utc>,
+    pub last_mod_update: Option<DateTime<Utc>>,
+    pub last_user_update: Option<DateTime<Utc>>,
+    /// Set while the conversation has unread messages.
+    pub last_unread: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub num_messages: i32,
+    /// The messages and mod actions in the order they happened.
+    #[serde(default, deserialize_with = "nullable")]
+    pub obj_ids: Vec<ModmailObjectId>,
+    pub owner: ModmailOwner,
+    /// The non moderator in the conversation, if there is one.
+    pub participant: Option<ModmailParticipant>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub authors: Vec<ModmailParticipant>,
+    pub state: Option<i32>,
+    pub conversation_type: Option<String>,
+
+    /// Any fields reddit sent that are not part of the model.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl ModmailConversationInfo {
+    pub fn is_unread(&self) -> bool {
+        self.last_unread.is_some()
+    }
+
+    /// Ids of the messages in the conversation, oldest first.
+    pub fn message_ids(&self) -> impl Iterator<Item = &str> {
+        self.obj_ids
+            .iter()
+            .filter(|o| o.key == "messages")
+            .map(|o| o.id.as_str())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailMessage {
+    pub id: String,
+    /// The message as html.
+    pub body: String,
+    pub body_markdown: String,
+    pub author: ModmailParticipant,
+    /// If the message is a private moderator note.
+    #[serde(default)]
+    pub is_internal: bool,
+    pub date: DateTime<Utc>,
+
+    /// Any fields reddit sent that are not part of the model.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// Puts the messages of `conversation` in the order they were sent.
+/// Messages that were not included in `messages` are skipped.
+pub(crate) fn order_messages(
+    conversation: &ModmailConversationInfo,
+    messages: &mut HashMap<String, ModmailMessage>,
+) -> Vec<ModmailMessage> {
+    conversation
+        .message_ids()
+        .filter_map(|id| messages.remove(id))
+        .collect()
+}
+
+/// Response from `GET api/mod/conversations`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailConversations {
+    pub conversations: HashMap<String, ModmailConversationInfo>,
+    /// The conversation ids in the requested sort order.
+    pub conversation_ids: Vec<String>,
+    /// The most recent message of each conversation.
+    pub messages: HashMap<String, ModmailMessage>,
+    pub viewer_id: Option<String>,
+}
+
+impl ModmailConversations {
+    /// The conversations in sort order, each with the messages that were sent with it.
+    pub fn into_ordered(self) -> Vec<(ModmailConversationInfo, Vec<ModmailMessage>)> {
+        let ModmailConversations {
+            mut conversations,
+            conversation_ids,
+            mut messages,
+            ..
+        } = self;
+
+        conversation_ids
+            .iter()
+            .filter_map(|id| conversations.remove(id))
+            .map(|c| {
+                let m = order_messages(&c, &mut messages);
+                (c, m)
+            })
+            .collect()
+    }
+}
+
+/// Response from `api/mod/conversations/<id>`
+/// and from replying to or creating a conversation.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ModmailConversationResponse {
+    pub conversation: ModmailConversationInfo,
+    #[serde(default, deserialize_with = "nullable")]
+    pub messages: HashMap<String, ModmailMessage>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub mod_actions: HashMap<String, Value>,
+    /// Details of the user the conversation is with.
+    pub user: Option<Value>,
+}
+
+impl ModmailConversationResponse {
+    pub fn into_parts(mut self) -> (ModmailConversationInfo, Vec<ModmailMessage>) {
+        let messages = order_messages(&self.conversation, &mut self.messages);
+        (self.conversation, messages)
+    }
+}
+
+/// Number of unread conversations in each folder.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ModmailUnreadCount {
+    pub new: i32,
+    pub inprogress: i32,
+    pub archived: i32,
+    pub highlighted: i32,
+    #[serde(rename = "mod")]
+    pub moderator: i32,
+    pub notifications: i32,
+    pub join_requests: i32,
+    pub appeals: i32,
+}
+
+impl ModmailUnreadCount {
+    pub fn total(&self) -> i32 {
+        self.new
+            + self.inprogress
+            + self.archived
+            + self.highlighted
+            + self.moderator
+            + self.notifications
+            + self.join_requests
+            + self.appeals
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ModmailBulkRead {
+    #[serde(default)]
+    pub conversation_ids: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendModmailReply<'a> {
+    pub body: &'a str,
+    /// Reply as the subreddit instead of the moderator.
+    pub is_author_hidden: bool,
+    /// Private note only visible to moderators.
+    pub is_internal: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendModmailCreate<'a> {
+    pub body: &'a str,
+    pub is_author_hidden: bool,
+    pub sr_name: &'a str,
+    pub subject: &'a str,
+    /// Username to message, not set for conversations between moderators.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendModmailRead<'a> {
+    /// Comma separated conversation ids.
+    pub conversation_ids: &'a str,
+}
+
+#[derive(Serialize)]
+pub struct SendModmailBulkRead<'a> {
+    /// Comma separated subreddit names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity: Option<&'a str>,
+    pub state: &'a str,
+}
+
+#[derive(Serialize)]
+pub struct SendModmailMute {
+    pub num_hours: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn conversation(id: &str, obj_ids: Value) -> Value {
+        json!({
+            "id": id,
+            "subject": format!("Conversation {}", id),
+            "lastUpdated": "2020-05-15T12:00:00.000000+00:00",
+            "lastModUpdate": null,
+            "lastUserUpdate": null,
+            "lastUnread": null,
+            "numMessages": 2,
+            "objIds": obj_ids,
+            "owner": {"displayName": "rust", "id": "t5_2s7lj", "type": "subreddit"},
+            "participant": null,
+            "state": 1,
+            "conversationType": "sr_user",
+        })
+    }
+
+    fn message(id: &str) -> Value {
+        json!({
+            "id": id,
+            "body": format!("<p>{}</p>", id),
+            "bodyMarkdown": id,
+            "author": {"name": "a_user"},
+            "date": "2020-05-15T12:00:00.000000+00:00",
+        })
+    }
+
+    #[test]
+    fn into_ordered_follows_conversation_ids() {
+        // Trimmed from `api/mod/conversations`
+        let conversations: ModmailConversations = serde_json::from_value(json!({
+            "conversations": {
+                "c1": conversation("c1", json!([
+                    {"id": "m2", "key": "messages"},
+                    {"id": "a1", "key": "modActions"},
+                    {"id": "m1", "key": "messages"},
+                ])),
+                "c2": conversation("c2", json!([{"id": "m3", "key": "messages"}])),
+                "c3": conversation("c3", json!(null)),
+            },
+            "conversationIds": ["c2", "c1", "missing", "c3"],
+            "messages": {
+                "m1": message("m1"),
+                "m2": message("m2"),
+                "m3": message("m3"),
+            },
+            "viewerId": "t2_viewer",
+        }))
+        .unwrap();
+
+        let ordered = conversations.into_ordered();
+        let ids: Vec<(&str, Vec<&str>)> = ordered
+            .iter()
+            .map(|(c, m)| (c.id.as_str(), m.iter().map(|m| m.id.as_str()).collect()))
+            .collect();
+        assert_eq!(
+            ids,
+            [("c2", vec!["m3"]), ("c1", vec!["m2", "m1"]), ("c3", vec![])]
+        );
+    }
+
+    #[test]
+    fn order_messages_skips_missing() {
+        let conversation: ModmailConversationInfo = serde_json::from_value(conversation(
+            "c1",
+            json!([
+                {"id": "m1", "key": "messages"},
+                {"id": "m2", "key": "messages"},
+            ]),
+        ))
+        .unwrap();
+        let mut messages = HashMap::new();
+        messages.insert(
+            "m2".to_owned(),
+            serde_json::from_value::<ModmailMessage>(message("m2")).unwrap(),
+        );
+
+        let ordered = order_messages(&conversation, &mut messages);
+        assert_eq!(ordered.len(), 1);
+        assert_eq!(ordered[0].id, "m2");
+        assert!(messages.is_empty());
+    }
+}
@@ -0,0 +1,22 @@
+use crate::models::timestamp;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MultiredditSubreddit {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MultiredditInfo {
+    pub name: String,
+    pub display_name: String,
+    pub path: String,
+    pub owner: Option<String>,
+    pub description_md: Option<String>,
+    pub visibility: Option<String>,
+    pub over_18: Option<bool>,
+    #[serde(with = "timestamp::option", default)]
+    pub created_utc: Option<DateTime<Utc>>,
+    pub subreddits: Vec<MultiredditSubreddit>,
+}
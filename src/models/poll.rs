@@ -0,0 +1,112 @@
+use crate::models::{nullable, timestamp, SubredditSubmit};
+use chrono::{DateTime, Utc};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Min and max number of options a poll can have.
+pub const POLL_OPTIONS_MIN: usize = 2;
+pub const POLL_OPTIONS_MAX: usize = 6;
+
+/// Min and max number of days a poll can run for.
+pub const POLL_DURATION_MIN: u32 = 1;
+pub const POLL_DURATION_MAX: u32 = 7;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct PollOption {
+    pub id: String,
+    pub text: String,
+    /// Only sent once the user has voted or the poll has ended.
+    pub vote_count: Option<i32>,
+}
+
+/// The `poll_data` of a poll post.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PollData {
+    #[serde(default)]
+    pub options: Vec<PollOption>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub total_vote_count: i32,
+    /// Not always sent, e.g. for some older polls.
+    #[serde(with = "timestamp::millis::option", default)]
+    pub voting_end_timestamp: Option<DateTime<Utc>>,
+    /// Id of the option the user voted for.
+    pub user_selection: Option<String>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub is_prediction: bool,
+
+    /// Any fields reddit sent that are not part of the model.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl PollData {
+    /// True once `voting_end_timestamp` has passed.
+    /// False if reddit did not send when the poll ends.
+    pub fn has_ended(&self) -> bool {
+        self.voting_end_timestamp
+            .map(|end| end <= Utc::now())
+            .unwrap_or(false)
+    }
+
+    /// The option the user voted for.
+    pub fn user_selection(&self) -> Option<&PollOption> {
+        let selected = self.user_selection.as_ref()?;
+        self.options.iter().find(|o| &o.id == selected)
+    }
+}
+
+/// Sent as json to `api/submit_poll_post`
+#[derive(Serialize)]
+pub struct SubmitPollPost<'a> {
+    #[serde(flatten)]
+    pub submit: SubredditSubmit<'a>,
+    pub text: &'a str,
+    pub options: &'a [&'a str],
+    /// Number of days the poll runs for.
+    pub duration: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn poll(end: Value) -> PollData {
+        let mut poll = json!({
+            "options": [{"id": "1", "text": "Yes"}, {"id": "2", "text": "No"}],
+            "total_vote_count": 10,
+            "user_selection": "2",
+        });
+        if !end.is_null() {
+            poll["voting_end_timestamp"] = end;
+        }
+        serde_json::from_value(poll).unwrap()
+    }
+
+    #[test]
+    fn voting_end_timestamp_in_millis() {
+        let poll = poll(json!(1589587200123i64));
+        assert_eq!(
+            poll.voting_end_timestamp.map(|t| t.timestamp_millis()),
+            Some(1589587200123)
+        );
+        assert!(poll.has_ended());
+        assert_eq!(poll.user_selection().map(|o| o.text.as_str()), Some("No"));
+
+        let future = Utc::now().timestamp_millis() + 60_000;
+        assert!(!self::poll(json!(future)).has_ended());
+    }
+
+    #[test]
+    fn missing_voting_end_timestamp() {
+        let missing = poll(Value::Null);
+        assert!(missing.voting_end_timestamp.is_none());
+        assert!(!missing.has_ended());
+
+        let null: PollData = serde_json::from_value(json!({"voting_end_timestamp": null})).unwrap();
+        assert!(null.voting_end_timestamp.is_none());
+        assert!(!null.has_ended());
+    }
+}
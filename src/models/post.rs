@@ -1,28 +1,34 @@
-use crate::models::{ModerateData, VoteData};
+use crate::models::{nullable, timestamp, ModerateData, PollData, VoteData};
+use chrono::{DateTime, Utc};
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 
-#[derive(Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct PostImage {
     pub url: String,
     pub width: Option<i32>,
     pub height: Option<i32>,
 }
 
-#[derive(Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct PostImages {
     pub source: PostImage,
+    #[serde(default)]
     pub resolutions: Vec<PostImage>,
+    #[serde(default)]
     pub id: String,
 }
 
-#[derive(Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct PostPreview {
     pub images: Vec<PostImages>,
+    #[serde(default)]
     pub enabled: bool,
 }
 
-#[derive(Deserialize, Debug, Clone, Default)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct PostInfo {
     #[serde(flatten)]
     pub vote_data: VoteData,
@@ -30,16 +36,16 @@ pub struct PostInfo {
     #[serde(flatten)]
     pub moderate_data: ModerateData,
 
-    #[serde(rename = "spoiler")]
+    #[serde(rename = "spoiler", default)]
     pub is_spoiler: bool,
 
-    #[serde(rename = "hidden")]
+    #[serde(rename = "hidden", default, deserialize_with = "nullable")]
     pub is_hidden: bool,
 
-    #[serde(rename = "is_self")]
+    #[serde(rename = "is_self", default)]
     pub is_self_posted: bool,
 
-    #[serde(rename = "over_18")]
+    #[serde(rename = "over_18", default)]
     pub nsfw: bool,
 
     pub author: String,
@@ -47,6 +53,7 @@ pub struct PostInfo {
     pub domain: Option<String>,
     pub link_flair_css_class: Option<String>,
     pub link_flair_text: Option<String>,
+    #[serde(default, deserialize_with = "nullable")]
     pub num_comments: i32,
     pub selftext: Option<String>,
     pub selftext_html: Option<String>,
@@ -55,13 +62,27 @@ pub struct PostInfo {
     pub title: String,
     pub subreddit: String,
     pub url: String,
+    #[serde(default, deserialize_with = "nullable")]
     pub is_crosspostable: bool,
+    #[serde(default, deserialize_with = "nullable")]
     pub num_crossposts: i32,
-    pub created: f64,
+    #[serde(with = "timestamp")]
+    pub created: DateTime<Utc>,
+    #[serde(with = "timestamp")]
+    pub created_utc: DateTime<Utc>,
     pub crosspost_parent: Option<String>,
+    /// Only set on poll posts.
+    pub poll_data: Option<PollData>,
+
+    /// Any fields reddit sent that are not part of the model.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
 use crate::feed::Feedable;
+use crate::models::ListingChild;
+impl ListingChild for PostInfo {}
+
 impl Feedable for PostInfo {
     fn feed_id(&self) -> String {
         self.moderate_data.name.clone()
@@ -79,5 +100,6 @@ pub struct PostSetFlair<'a> {
 #[derive(Serialize)]
 pub struct PostEditText<'a> {
     pub thing_id: &'a str,
+    #[serde(rename = "text")]
     pub new_text: &'a str,
 }
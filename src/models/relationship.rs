@@ -0,0 +1,143 @@
+use crate::models::listing::parse_children;
+use crate::models::{timestamp, InvalidChild, ListingChild};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Longest temporary ban reddit allows, in days.
+pub const BAN_DURATION_MAX: u32 = 999;
+
+/// How a user is related to a subreddit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RelationshipType {
+    Banned,
+    Muted,
+    /// An approved user.
+    Contributor,
+    WikiBanned,
+    WikiContributor,
+    Moderator,
+    ModeratorInvite,
+}
+
+impl RelationshipType {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            RelationshipType::Banned => "banned",
+            RelationshipType::Muted => "muted",
+            RelationshipType::Contributor => "contributor",
+            RelationshipType::WikiBanned => "wikibanned",
+            RelationshipType::WikiContributor => "wikicontributor",
+            RelationshipType::Moderator => "moderator",
+            RelationshipType::ModeratorInvite => "moderator_invite",
+        }
+    }
+}
+
+/// A user in one of a subreddits relationship lists,
+/// e.g. `r/<subreddit>/about/banned`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RelationshipInfo {
+    /// Username of the user.
+    pub name: String,
+    /// Fullname of the user. e.g. `t2_...`
+    pub id: String,
+    /// Id of the relationship, used for pagination. e.g. `rb_...`
+    pub rel_id: String,
+    /// When the user was added to the list.
+    #[serde(with = "timestamp")]
+    pub date: DateTime<Utc>,
+    /// Moderator note, only set for bans and mutes.
+    pub note: Option<String>,
+    /// Days left on a temporary ban, None if the ban is permanent.
+    pub days_left: Option<u32>,
+
+    /// Any fields reddit sent that are not part of the model.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl ListingChild for RelationshipInfo {
+    // Relationship lists are not wrapped in `{"kind": ..., "data": ...}`
+    fn from_children(children: Vec<Value>) -> (Vec<Self>, Vec<InvalidChild>) {
+        parse_children(children)
+    }
+}
+
+#[derive(Serialize)]
+pub struct SendFriend<'a> {
+    pub name: &'a str,
+    #[serde(rename = "type")]
+    pub relationship: &'a str,
+    /// Ban length in days, permanent if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u32>,
+    /// Moderator note, not shown to the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<&'a str>,
+    /// Reason for a ban, one of the subreddits rules.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_reason: Option<&'a str>,
+    /// Message sent to the banned user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_message: Option<&'a str>,
+    /// Fullname of the post or comment the user is banned for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ban_context: Option<&'a str>,
+    /// Comma separated moderator permissions, e.g. `+posts,-wiki`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<&'a str>,
+}
+
+impl<'a> SendFriend<'a> {
+    pub fn new(name: &'a str, relationship: RelationshipType) -> Self {
+        Self {
+            name,
+            relationship: relationship.to_str(),
+            duration: None,
+            note: None,
+            ban_reason: None,
+            ban_message: None,
+            ban_context: None,
+            permissions: None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct SendUnfriend<'a> {
+    pub name: &'a str,
+    #[serde(rename = "type")]
+    pub relationship: &'a str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn relationship_children() {
+        // Trimmed from `r/<subreddit>/about/banned`
+        let (banned, invalid) = RelationshipInfo::from_children(vec![
+            json!({
+                "name": "a_user",
+                "id": "t2_abc",
+                "rel_id": "rb_123",
+                "date": 1589500000.0,
+                "note": "spam",
+                "days_left": 3,
+            }),
+            json!({"name": "no_date", "id": "t2_def", "rel_id": "rb_456"}),
+        ]);
+        assert_eq!(banned.len(), 1);
+        assert_eq!(banned[0].name, "a_user");
+        assert_eq!(banned[0].days_left, Some(3));
+
+        assert_eq!(invalid.len(), 1);
+        assert_eq!(invalid[0].data["name"], "no_date");
+        assert!(invalid[0].error.contains("date"), "{}", invalid[0].error);
+    }
+}
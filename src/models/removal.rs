@@ -0,0 +1,246 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A removal reason a subreddit's moderators have set up.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RemovalReason {
+    pub id: String,
+    pub title: String,
+    /// The message sent to the author. Can contain `{author}`,
+    /// `{subreddit}`, `{title}`, `{kind}` and `{url}`/`{link}` placeholders.
+    pub message: String,
+
+    /// Any fields reddit sent that are not part of the model.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// Values substituted into a [RemovalReason] message.
+#[derive(Debug, Clone, Default)]
+pub struct RemovalContext {
+    pub author: String,
+    pub subreddit: String,
+    /// Title of the post, or the post the comment is in.
+    pub title: String,
+    /// "submission" or "comment"
+    pub kind: String,
+    /// Permalink of the removed post or comment.
+    pub url: String,
+}
+
+impl RemovalReason {
+    /// The message with its placeholders filled in from `context`.
+    pub fn render(&self, context: &RemovalContext) -> String {
+        render_template(&self.message, context)
+    }
+}
+
+/// Fills in the `{author}`, `{subreddit}`, `{title}`, `{kind}`
+/// and `{url}`/`{link}` placeholders of a removal message.
+/// Substituted values are not scanned again, so a title like `{url}` stays as it is.
+pub fn render_template(template: &str, context: &RemovalContext) -> String {
+    let placeholders = [
+        ("{author}", &context.author),
+        ("{subreddit}", &context.subreddit),
+        ("{title}", &context.title),
+        ("{kind}", &context.kind),
+        ("{url}", &context.url),
+        ("{link}", &context.url),
+    ];
+
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        match placeholders.iter().find(|(p, _)| rest.starts_with(p)) {
+            Some((placeholder, value)) => {
+                out.push_str(value);
+                rest = &rest[placeholder.len()..];
+            }
+            None => {
+                out.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Response from `api/v1/<subreddit>/removal_reasons`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RemovalReasons {
+    pub data: HashMap<String, RemovalReason>,
+    /// Ids of the reasons in the order moderators have set.
+    pub order: Vec<String>,
+}
+
+impl RemovalReasons {
+    /// The reasons in the order moderators have set.
+    pub fn into_ordered(self) -> Vec<RemovalReason> {
+        let mut data = self.data;
+        self.order
+            .iter()
+            .filter_map(|id| data.remove(id))
+            .collect()
+    }
+}
+
+#[derive(Serialize)]
+pub struct SendRemovalReason<'a> {
+    pub title: &'a str,
+    pub message: &'a str,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RemovalReasonCreated {
+    pub id: String,
+}
+
+/// How a removal message is sent to the author.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum RemovalMessageType {
+    /// A distinguished reply from the moderator.
+    Public,
+    /// A distinguished reply from the subreddit's mod account.
+    PublicAsSubreddit,
+    /// A modmail message to the author that does not show which moderator sent it.
+    Private,
+    /// A modmail message to the author from the moderator.
+    PrivateExposed,
+}
+
+impl RemovalMessageType {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            RemovalMessageType::Public => "public",
+            RemovalMessageType::PublicAsSubreddit => "public_as_subreddit",
+            RemovalMessageType::Private => "private",
+            RemovalMessageType::PrivateExposed => "private_exposed",
+        }
+    }
+}
+
+/// Sent as json in the `json` form field of `api/v1/modactions/removal_reasons`
+#[derive(Serialize)]
+pub struct ApplyRemovalReason<'a> {
+    pub item_ids: Vec<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mod_note: Option<&'a str>,
+}
+
+/// Sent as json in the `json` form field of
+/// `api/v1/modactions/removal_link_message` and `removal_comment_message`
+#[derive(Serialize)]
+pub struct SendRemovalMessage<'a> {
+    pub item_id: Vec<&'a str>,
+    pub message: &'a str,
+    /// Subject of private messages.
+    pub title: &'a str,
+    #[serde(rename = "type")]
+    pub message_type: &'a str,
+}
+
+/// A form with a single `json` field holding a json encoded body.
+#[derive(Serialize)]
+pub struct SendJsonForm {
+    pub json: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn context() -> RemovalContext {
+        RemovalContext {
+            author: "a_user".to_owned(),
+            subreddit: "rust".to_owned(),
+            title: "My first crate".to_owned(),
+            kind: "submission".to_owned(),
+            url: "https://www.reddit.com/r/rust/comments/gkxqzq/".to_owned(),
+        }
+    }
+
+    #[test]
+    fn render_template_fills_placeholders() {
+        let message = render_template(
+            "Hi u/{author}, your {kind} \"{title}\" ({url}) was removed from r/{subreddit}. {link}",
+            &context(),
+        );
+        assert_eq!(
+            message,
+            "Hi u/a_user, your submission \"My first crate\" \
+             (https://www.reddit.com/r/rust/comments/gkxqzq/) was removed from r/rust. \
+             https://www.reddit.com/r/rust/comments/gkxqzq/"
+        );
+    }
+
+    #[test]
+    fn render_template_keeps_unknown_braces() {
+        let message = render_template("{ {unknown} {author {{author}}", &context());
+        assert_eq!(message, "{ {unknown} {author {a_user}");
+    }
+
+    #[test]
+    fn render_template_does_not_expand_values() {
+        let context = RemovalContext {
+            title: "{url} by {author}".to_owned(),
+            author: "{subreddit}".to_owned(),
+            ..context()
+        };
+        let message = render_template("{title} was removed, u/{author}", &context);
+        assert_eq!(message, "{url} by {author} was removed, u/{subreddit}");
+    }
+
+    #[test]
+    fn removal_reasons_into_ordered() {
+        // Trimmed from `api/v1/<subreddit>/removal_reasons`
+        let reasons: RemovalReasons = serde_json::from_value(json!({
+            "data": {
+                "r1": {"id": "r1", "title": "Spam", "message": "No spam"},
+                "r2": {"id": "r2", "title": "Off topic", "message": "Stay on topic"},
+            },
+            "order": ["r2", "missing", "r1"],
+        }))
+        .unwrap();
+        let ids: Vec<String> = reasons.into_ordered().into_iter().map(|r| r.id).collect();
+        assert_eq!(ids, ["r2", "r1"]);
+    }
+
+    #[test]
+    fn apply_removal_reason_json() {
+        let apply = ApplyRemovalReason {
+            item_ids: vec!["t3_gkxqzq"],
+            reason_id: Some("r1"),
+            mod_note: None,
+        };
+        assert_eq!(
+            serde_json::to_value(&apply).unwrap(),
+            json!({"item_ids": ["t3_gkxqzq"], "reason_id": "r1"})
+        );
+    }
+
+    #[test]
+    fn send_removal_message_json() {
+        let send = SendRemovalMessage {
+            item_id: vec!["t1_fqxyz12"],
+            message: "Removed for spam",
+            title: "Your comment was removed",
+            message_type: RemovalMessageType::PrivateExposed.to_str(),
+        };
+        assert_eq!(
+            serde_json::to_value(&send).unwrap(),
+            json!({
+                "item_id": ["t1_fqxyz12"],
+                "message": "Removed for spam",
+                "title": "Your comment was removed",
+                "type": "private_exposed",
+            })
+        );
+    }
+}
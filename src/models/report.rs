@@ -0,0 +1,175 @@
+//! Reports are sent as arrays rather than objects.
+//! e.g. `"mod_reports": [["spam", "a_moderator"]]`
+//! and `"user_reports": [["spam", 2, false, false]]`
+use serde::de::{self, Deserializer};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A report made by a moderator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModReport {
+    pub reason: Option<String>,
+    pub moderator: String,
+}
+
+/// Reports made by users, grouped by reason.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserReport {
+    pub reason: Option<String>,
+    pub count: i32,
+}
+
+fn reason_of(value: Option<&Value>) -> Option<String> {
+    value.and_then(Value::as_str).map(str::to_owned)
+}
+
+impl<'de> Deserialize<'de> for ModReport {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = Vec::<Value>::deserialize(deserializer)?;
+        let moderator = fields
+            .get(1)
+            .and_then(Value::as_str)
+            .ok_or_else(|| de::Error::custom("mod report has no moderator"))?;
+        Ok(ModReport {
+            reason: reason_of(fields.first()),
+            moderator: moderator.to_owned(),
+        })
+    }
+}
+
+impl Serialize for ModReport {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.reason, &self.moderator).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for UserReport {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = Vec::<Value>::deserialize(deserializer)?;
+        let count = fields
+            .get(1)
+            .and_then(Value::as_i64)
+            .ok_or_else(|| de::Error::custom("user report has no count"))?;
+        Ok(UserReport {
+            reason: reason_of(fields.first()),
+            count: count as i32,
+        })
+    }
+}
+
+impl Serialize for UserReport {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.reason, self.count).serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ModerateData;
+    use serde_json::json;
+
+    #[test]
+    fn mod_reports() {
+        let reports: Vec<ModReport> = serde_json::from_value(json!([
+            ["Breaks rule 2", "a_moderator"],
+            [null, "another_mod"]
+        ]))
+        .unwrap();
+        assert_eq!(
+            reports,
+            [
+                ModReport {
+                    reason: Some("Breaks rule 2".to_owned()),
+                    moderator: "a_moderator".to_owned(),
+                },
+                ModReport {
+                    reason: None,
+                    moderator: "another_mod".to_owned(),
+                },
+            ]
+        );
+
+        assert!(serde_json::from_value::<ModReport>(json!(["spam"])).is_err());
+        assert!(serde_json::from_value::<ModReport>(json!({"reason": "spam"})).is_err());
+    }
+
+    #[test]
+    fn user_reports() {
+        let reports: Vec<UserReport> = serde_json::from_value(json!([
+            ["This is spam", 3, false, false],
+            [null, 1, false, false],
+            ["It's rude", 1]
+        ]))
+        .unwrap();
+        assert_eq!(
+            reports,
+            [
+                UserReport {
+                    reason: Some("This is spam".to_owned()),
+                    count: 3,
+                },
+                UserReport {
+                    reason: None,
+                    count: 1,
+                },
+                UserReport {
+                    reason: Some("It's rude".to_owned()),
+                    count: 1,
+                },
+            ]
+        );
+
+        assert!(serde_json::from_value::<UserReport>(json!(["spam", "3"])).is_err());
+    }
+
+    #[test]
+    fn reports_round_trip() {
+        let mod_report: ModReport = serde_json::from_value(json!(["spam", "a_mod"])).unwrap();
+        assert_eq!(
+            serde_json::to_value(&mod_report).unwrap(),
+            json!(["spam", "a_mod"])
+        );
+
+        let user_report: UserReport =
+            serde_json::from_value(json!(["spam", 2, false, false])).unwrap();
+        assert_eq!(
+            serde_json::to_value(&user_report).unwrap(),
+            json!(["spam", 2])
+        );
+    }
+
+    #[test]
+    fn moderate_data_reports() {
+        // Trimmed from a comment in `r/<subreddit>/about/reports`.
+        let data: ModerateData = serde_json::from_value(json!({
+            "name": "t1_fqxyz12",
+            "author_flair_css_class": null,
+            "author_flair_text": null,
+            "approved_at_utc": null,
+            "banned_at_utc": null,
+            "num_reports": 4,
+            "mod_reports": [["Breaks rule 1", "a_moderator"]],
+            "user_reports": [["Spam", 2, false, false], ["Harassment", 1, false, false]],
+        }))
+        .unwrap();
+        assert_eq!(data.num_reports, Some(4));
+        assert_eq!(data.mod_reports.len(), 1);
+        assert_eq!(data.user_reports.iter().map(|r| r.count).sum::<i32>(), 3);
+
+        // Reports are null for users that are not moderators.
+        let data: ModerateData = serde_json::from_value(json!({
+            "name": "t1_fqxyz12",
+            "author_flair_css_class": null,
+            "author_flair_text": null,
+            "num_reports": null,
+            "mod_reports": null,
+            "user_reports": null,
+        }))
+        .unwrap();
+        assert!(data.mod_reports.is_empty());
+        assert!(data.user_reports.is_empty());
+        assert_eq!(data.num_reports, None);
+    }
+}
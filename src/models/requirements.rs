@@ -0,0 +1,147 @@
+use crate::models::nullable;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum BodyRestrictionPolicy {
+    #[default]
+    None,
+    Required,
+    NotAllowed,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum LinkRestrictionPolicy {
+    #[default]
+    None,
+    Whitelist,
+    Blacklist,
+    #[serde(other)]
+    Unknown,
+}
+
+/// If gallery items need a caption or outbound url.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum GalleryRequirement {
+    #[default]
+    None,
+    Optional,
+    Required,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Response from `api/v1/<subreddit>/post_requirements`
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct PostRequirements {
+    pub title_text_min_length: Option<usize>,
+    pub title_text_max_length: Option<usize>,
+    /// The title has to contain at least one of these.
+    #[serde(default, deserialize_with = "nullable")]
+    pub title_required_strings: Vec<String>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub title_blacklisted_strings: Vec<String>,
+    /// The title has to match at least one of these.
+    #[serde(default, deserialize_with = "nullable")]
+    pub title_regexes: Vec<String>,
+
+    #[serde(default, deserialize_with = "nullable")]
+    pub body_restriction_policy: BodyRestrictionPolicy,
+    pub body_text_min_length: Option<usize>,
+    pub body_text_max_length: Option<usize>,
+    /// The body has to contain at least one of these.
+    #[serde(default, deserialize_with = "nullable")]
+    pub body_required_strings: Vec<String>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub body_blacklisted_strings: Vec<String>,
+    /// The body has to match at least one of these.
+    #[serde(default, deserialize_with = "nullable")]
+    pub body_regexes: Vec<String>,
+
+    #[serde(default, deserialize_with = "nullable")]
+    pub link_restriction_policy: LinkRestrictionPolicy,
+    #[serde(default, deserialize_with = "nullable")]
+    pub domain_whitelist: Vec<String>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub domain_blacklist: Vec<String>,
+    /// Number of days before a link can be posted again.
+    pub link_repost_age: Option<u32>,
+
+    #[serde(default, deserialize_with = "nullable")]
+    pub is_flair_required: bool,
+
+    pub gallery_min_items: Option<usize>,
+    pub gallery_max_items: Option<usize>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub gallery_captions_requirement: GalleryRequirement,
+    #[serde(default, deserialize_with = "nullable")]
+    pub gallery_urls_requirement: GalleryRequirement,
+
+    pub guidelines_text: Option<String>,
+
+    /// Any fields reddit sent that are not part of the model.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// A reason a post would be rejected by [PostRequirements].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PostRequirementViolation {
+    TitleTooShort { min: usize, len: usize },
+    TitleTooLong { max: usize, len: usize },
+    TitleMissingRequiredString(Vec<String>),
+    TitleBlacklistedString(String),
+    TitleRegexMismatch(Vec<String>),
+    BodyRequired,
+    BodyNotAllowed,
+    BodyTooShort { min: usize, len: usize },
+    BodyTooLong { max: usize, len: usize },
+    BodyMissingRequiredString(Vec<String>),
+    BodyBlacklistedString(String),
+    BodyRegexMismatch(Vec<String>),
+    DomainNotWhitelisted(String),
+    DomainBlacklisted(String),
+    FlairRequired,
+    GalleryTooFewItems { min: usize, len: usize },
+    GalleryTooManyItems { max: usize, len: usize },
+    GalleryCaptionRequired,
+    GalleryUrlRequired,
+    /// The link was posted within the repost age, with the fullname of the post.
+    Repost(String),
+}
+
+impl fmt::Display for PostRequirementViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use PostRequirementViolation::*;
+        match self {
+            TitleTooShort { min, len } => write!(f, "title is {} characters, min is {}", len, min),
+            TitleTooLong { max, len } => write!(f, "title is {} characters, max is {}", len, max),
+            TitleMissingRequiredString(s) => write!(f, "title must contain one of: {}", s.join(", ")),
+            TitleBlacklistedString(s) => write!(f, "title contains \"{}\"", s),
+            TitleRegexMismatch(r) => write!(f, "title must match one of: {}", r.join(", ")),
+            BodyRequired => write!(f, "a body is required"),
+            BodyNotAllowed => write!(f, "a body is not allowed"),
+            BodyTooShort { min, len } => write!(f, "body is {} characters, min is {}", len, min),
+            BodyTooLong { max, len } => write!(f, "body is {} characters, max is {}", len, max),
+            BodyMissingRequiredString(s) => write!(f, "body must contain one of: {}", s.join(", ")),
+            BodyBlacklistedString(s) => write!(f, "body contains \"{}\"", s),
+            BodyRegexMismatch(r) => write!(f, "body must match one of: {}", r.join(", ")),
+            DomainNotWhitelisted(d) => write!(f, "{} is not an allowed domain", d),
+            DomainBlacklisted(d) => write!(f, "{} is a blocked domain", d),
+            FlairRequired => write!(f, "flair is required"),
+            GalleryTooFewItems { min, len } => write!(f, "gallery has {} items, min is {}", len, min),
+            GalleryTooManyItems { max, len } => write!(f, "gallery has {} items, max is {}", len, max),
+            GalleryCaptionRequired => write!(f, "gallery items need a caption"),
+            GalleryUrlRequired => write!(f, "gallery items need an outbound url"),
+            Repost(name) => write!(f, "link was already posted in {}", name),
+        }
+    }
+}
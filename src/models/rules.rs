@@ -0,0 +1,150 @@
+use crate::models::{timestamp, ThingKind};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io;
+
+/// Max number of characters in a [ReportReason::Other] reason.
+pub const REPORT_REASON_MAX: usize = 100;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SubredditRule {
+    /// What the rule applies to. `"link"`, `"comment"` or `"all"`
+    pub kind: String,
+    pub short_name: String,
+    pub description: String,
+    pub description_html: Option<String>,
+    /// The reason shown to users when reporting. Falls back to `short_name`.
+    pub violation_reason: Option<String>,
+    pub priority: i32,
+    #[serde(with = "timestamp::option", default)]
+    pub created_utc: Option<DateTime<Utc>>,
+}
+
+impl SubredditRule {
+    /// If the rule can be used to report things of `kind`
+    pub fn applies_to(&self, kind: ThingKind) -> bool {
+        matches!(
+            (self.kind.as_str(), kind),
+            ("all", _) | ("link", ThingKind::Link) | ("comment", ThingKind::Comment)
+        )
+    }
+
+    /// The text to use for [ReportReason::SubredditRule]
+    pub fn report_reason(&self) -> &str {
+        self.violation_reason.as_deref().unwrap_or(&self.short_name)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SubredditRules {
+    pub rules: Vec<SubredditRule>,
+    #[serde(default)]
+    pub site_rules: Vec<String>,
+}
+
+impl SubredditRules {
+    /// All of the reasons a thing of `kind` can be reported for
+    /// in the subreddit, its own rules first then reddits site rules.
+    pub fn report_reasons(&self, kind: ThingKind) -> Vec<ReportReason<'_>> {
+        let subreddit_rules = self
+            .rules
+            .iter()
+            .filter(|r| r.applies_to(kind))
+            .map(|r| ReportReason::SubredditRule(r.report_reason()));
+        let site_rules = self
+            .site_rules
+            .iter()
+            .map(|r| ReportReason::SiteRule(r.as_str()));
+
+        subreddit_rules.chain(site_rules).collect()
+    }
+}
+
+/// Why a post or comment is being reported.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReportReason<'a> {
+    /// A free-form reason of at most [REPORT_REASON_MAX] characters,
+    /// longer reasons fail with `InvalidInput` before anything is sent.
+    Other(&'a str),
+    /// One of the subreddits rules. See [SubredditRule::report_reason]
+    SubredditRule(&'a str),
+    /// One of reddits site wide rules. See [SubredditRules::site_rules]
+    SiteRule(&'a str),
+}
+
+impl ReportReason<'_> {
+    /// Checks the reason can be sent without reddit rejecting it.
+    pub fn validate(&self) -> io::Result<()> {
+        if let ReportReason::Other(reason) = self {
+            let len = reason.chars().count();
+            if len > REPORT_REASON_MAX {
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "A report reason can be at most {} characters, got {}",
+                        REPORT_REASON_MAX, len
+                    ),
+                ))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize)]
+pub struct SendReport<'a> {
+    pub thing_id: &'a str,
+    pub reason: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub other_reason: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_reason: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub site_reason: Option<&'a str>,
+}
+
+impl<'a> SendReport<'a> {
+    pub fn new(thing_id: &'a str, reason: ReportReason<'a>) -> Self {
+        let mut report = SendReport {
+            thing_id,
+            reason: "other",
+            other_reason: None,
+            rule_reason: None,
+            site_reason: None,
+        };
+        match reason {
+            ReportReason::Other(r) => report.other_reason = Some(r),
+            ReportReason::SubredditRule(r) => {
+                report.reason = r;
+                report.rule_reason = Some(r);
+            }
+            ReportReason::SiteRule(r) => {
+                report.reason = r;
+                report.site_reason = Some(r);
+            }
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn other_reason_length() {
+        let max = "a".repeat(REPORT_REASON_MAX);
+        assert!(ReportReason::Other(&max).validate().is_ok());
+
+        // Counted in characters, not bytes.
+        let multibyte = "é".repeat(REPORT_REASON_MAX);
+        assert!(ReportReason::Other(&multibyte).validate().is_ok());
+
+        let long = "a".repeat(REPORT_REASON_MAX + 1);
+        let err = ReportReason::Other(&long).validate().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        assert!(ReportReason::SubredditRule(&long).validate().is_ok());
+        assert!(ReportReason::SiteRule(&long).validate().is_ok());
+    }
+}
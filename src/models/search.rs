@@ -1,8 +1,9 @@
 use crate::models::ListingData;
 
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(bound(deserialize = "T: DeserializeOwned"))]
 pub struct SearchInfo<T> {
     #[serde(flatten)]
     pub results: ListingData<T>,
@@ -1,13 +1,23 @@
+use crate::models::{nullable, timestamp};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SubredditInfo {
-    pub created: Option<f64>,
+    #[serde(with = "timestamp::option", default)]
+    pub created: Option<DateTime<Utc>>,
+    #[serde(with = "timestamp::option", default)]
+    pub created_utc: Option<DateTime<Utc>>,
+    #[serde(default)]
     pub description: String,
     pub description_html: Option<String>,
     pub display_name: String,
+    pub name: String,
     pub header_img: Option<String>,
     pub header_title: Option<String>,
+    #[serde(default)]
     pub public_description: String,
     pub subscribers: Option<i32>,
     pub accounts_active: Option<i32>,
@@ -16,17 +26,33 @@ pub struct SubredditInfo {
     pub user_is_moderator: Option<bool>,
     pub mod_permissions: Option<i32>,
     pub user_is_banned: Option<bool>,
+
+    /// Any fields reddit sent that are not part of the model.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
 
 #[derive(Serialize)]
 pub struct SubredditSubmit<'a> {
-    ///  "link", "self" or "image"
+    ///  "link", "self", "image" or "crosspost"
     pub kind: &'a str,
     /// Subreddit
     pub sr : &'a str,
     pub title:  &'a str,
     pub resubmit: bool,
+    pub nsfw: bool,
+    pub spoiler: bool,
+    pub sendreplies: bool,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub flair_id: Option<&'a str>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub flair_text: Option<&'a str>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub collection_id: Option<&'a str>,
+    /// "CHAT" for a live chat post.
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub discussion_type: Option<&'a str>,
     #[serde(skip_serializing_if="Option::is_none")]
     pub iden:  Option<&'a str>,
     #[serde(skip_serializing_if="Option::is_none")]
@@ -36,9 +62,31 @@ pub struct SubredditSubmit<'a> {
 #[derive(Deserialize, Clone, Debug)]
 pub struct SubredditSubmitResponse {
     pub url: String,
+    /// The id, or the fullname for poll and gallery posts.
     pub id: String,
+    /// Not sent for poll and gallery posts, use [SubredditSubmitResponse::fullname].
+    #[serde(default)]
     pub name: String,
     pub drafts_count: Option<i32>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub warnings: Vec<Value>,
+
+    /// Any fields reddit sent that are not part of the model.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl SubredditSubmitResponse {
+    /// The fullname of the new post. e.g. `t3_gkxqzq`
+    pub fn fullname(&self) -> String {
+        if !self.name.is_empty() {
+            self.name.clone()
+        } else if self.id.starts_with("t3_") {
+            self.id.clone()
+        } else {
+            format!("t3_{}", self.id)
+        }
+    }
 }
 
 
@@ -56,3 +104,11 @@ pub struct SubredditSubmitText<'a> {
     pub text:  &'a str,
 }
 
+
+#[derive(Serialize)]
+pub struct SubredditSubmitCrosspost<'a> {
+    #[serde(flatten)]
+    pub submit: SubredditSubmit<'a>,
+    /// Fullname of the post being crossposted.
+    pub crosspost_fullname:  &'a str,
+}
@@ -0,0 +1,285 @@
+//! Serde helpers for reddit's unix timestamps.
+//!
+//! Reddit sends timestamps as seconds, usually as floats
+//! e.g. `"created_utc": 1589587200.0`
+//!
+//! ```
+//! # use chrono::{DateTime, Utc};
+//! # use serde::Deserialize;
+//! # use snoo::models::timestamp;
+//! # #[derive(Deserialize)]
+//! # struct Example {
+//! #[serde(with = "timestamp")]
+//! pub created_utc: DateTime<Utc>,
+//!
+//! #[serde(with = "timestamp::option", default)]
+//! pub approved_at_utc: Option<DateTime<Utc>>,
+//!
+//! #[serde(with = "timestamp::millis")]
+//! pub created_at: DateTime<Utc>,
+//!
+//! #[serde(with = "timestamp::millis::option", default)]
+//! pub voting_end_timestamp: Option<DateTime<Utc>>,
+//! # }
+//! ```
+use chrono::{DateTime, Utc};
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::Serializer;
+use std::fmt;
+
+struct TimestampVisitor;
+
+impl<'de> Visitor<'de> for TimestampVisitor {
+    type Value = DateTime<Utc>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a unix timestamp in seconds")
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        // Floor so the nanoseconds are always positive, e.g. -1.5 is -2s + 0.5s
+        let floor = value.floor();
+        let mut secs = floor as i64;
+        let mut nanos = ((value - floor) * 1e9).round() as u32;
+        if nanos >= 1_000_000_000 {
+            secs += 1;
+            nanos -= 1_000_000_000;
+        }
+        DateTime::from_timestamp(secs, nanos)
+            .ok_or_else(|| E::custom(format!("timestamp out of range: {}", value)))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        DateTime::from_timestamp(value, 0)
+            .ok_or_else(|| E::custom(format!("timestamp out of range: {}", value)))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        self.visit_i64(value as i64)
+    }
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Utc>, D::Error> {
+    deserializer.deserialize_any(TimestampVisitor)
+}
+
+pub fn serialize<S: Serializer>(time: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error> {
+    let secs = time.timestamp() as f64 + f64::from(time.timestamp_subsec_nanos()) / 1e9;
+    serializer.serialize_f64(secs)
+}
+
+/// Same as [timestamp](self) for fields that can be `null` or missing.
+pub mod option {
+    use super::TimestampVisitor;
+    use chrono::{DateTime, Utc};
+    use serde::de::{self, Deserializer, Visitor};
+    use serde::ser::Serializer;
+    use std::fmt;
+
+    struct OptionVisitor;
+
+    impl<'de> Visitor<'de> for OptionVisitor {
+        type Value = Option<DateTime<Utc>>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a unix timestamp in seconds or null")
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+            super::deserialize(deserializer).map(Some)
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+            TimestampVisitor.visit_f64(value).map(Some)
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            TimestampVisitor.visit_i64(value).map(Some)
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            TimestampVisitor.visit_u64(value).map(Some)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<DateTime<Utc>>, D::Error> {
+        deserializer.deserialize_option(OptionVisitor)
+    }
+
+    pub fn serialize<S: Serializer>(
+        time: &Option<DateTime<Utc>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match time {
+            Some(time) => super::serialize(time, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+/// For the few newer apis that send timestamps
+/// as milliseconds instead of seconds.
+pub mod millis {
+    use super::TimestampVisitor;
+    use chrono::{DateTime, Utc};
+    use serde::de::{self, Deserializer, Visitor};
+    use serde::ser::Serializer;
+    use std::fmt;
+
+    struct MillisVisitor;
+
+    impl<'de> Visitor<'de> for MillisVisitor {
+        type Value = DateTime<Utc>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a unix timestamp in milliseconds")
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+            TimestampVisitor.visit_f64(value / 1000.0)
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            DateTime::from_timestamp_millis(value)
+                .ok_or_else(|| E::custom(format!("timestamp out of range: {}", value)))
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            self.visit_i64(value as i64)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Utc>, D::Error> {
+        deserializer.deserialize_any(MillisVisitor)
+    }
+
+    pub fn serialize<S: Serializer>(time: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(time.timestamp_millis())
+    }
+
+    /// Same as [millis](self) for fields that can be `null` or missing.
+    pub mod option {
+        use super::MillisVisitor;
+        use chrono::{DateTime, Utc};
+        use serde::de::{self, Deserializer, Visitor};
+        use serde::ser::Serializer;
+        use std::fmt;
+
+        struct OptionVisitor;
+
+        impl<'de> Visitor<'de> for OptionVisitor {
+            type Value = Option<DateTime<Utc>>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a unix timestamp in milliseconds or null")
+            }
+
+            fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(None)
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+                Ok(None)
+            }
+
+            fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+                super::deserialize(deserializer).map(Some)
+            }
+
+            fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+                MillisVisitor.visit_f64(value).map(Some)
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                MillisVisitor.visit_i64(value).map(Some)
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                MillisVisitor.visit_u64(value).map(Some)
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<DateTime<Utc>>, D::Error> {
+            deserializer.deserialize_option(OptionVisitor)
+        }
+
+        pub fn serialize<S: Serializer>(
+            time: &Option<DateTime<Utc>>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match time {
+                Some(time) => super::serialize(time, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    #[derive(Serialize, Deserialize)]
+    struct Seconds {
+        #[serde(with = "super")]
+        time: DateTime<Utc>,
+    }
+
+    fn parse(value: serde_json::Value) -> DateTime<Utc> {
+        serde_json::from_value::<Seconds>(json!({ "time": value }))
+            .unwrap()
+            .time
+    }
+
+    #[test]
+    fn fractional_seconds() {
+        let time = parse(json!(1589587200.25));
+        assert_eq!(time.timestamp(), 1589587200);
+        assert_eq!(time.timestamp_subsec_millis(), 250);
+
+        assert_eq!(parse(json!(1589587200)).timestamp(), 1589587200);
+        assert_eq!(parse(json!(1589587200.0)).timestamp(), 1589587200);
+    }
+
+    #[test]
+    fn negative_seconds() {
+        let time = parse(json!(-1.5));
+        assert_eq!(time.timestamp(), -2);
+        assert_eq!(time.timestamp_subsec_millis(), 500);
+        assert_eq!(time.timestamp_millis(), -1500);
+
+        assert_eq!(parse(json!(-1.0)).timestamp_millis(), -1000);
+        assert_eq!(parse(json!(-0.25)).timestamp_millis(), -250);
+    }
+
+    #[test]
+    fn nanos_round_up_to_next_second() {
+        let time = parse(json!(9.9999999999));
+        assert_eq!(time.timestamp(), 10);
+        assert_eq!(time.timestamp_subsec_nanos(), 0);
+    }
+
+    #[test]
+    fn round_trip() {
+        for value in &[json!(1589587200.5), json!(-1.5)] {
+            let seconds = Seconds {
+                time: parse(value.clone()),
+            };
+            assert_eq!(serde_json::to_value(&seconds).unwrap()["time"], *value);
+        }
+    }
+}
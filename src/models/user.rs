@@ -1,21 +1,45 @@
-use serde::Deserialize;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 
-use crate::models::SubredditInfo;
+use crate::models::{nullable, timestamp, SubredditInfo};
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct UserInfo {
+    #[serde(default)]
+    pub is_suspended: bool,
+    #[serde(default)]
     pub is_gold: bool,
+    #[serde(default)]
     pub verified: bool,
     pub name: String,
+    #[serde(default)]
     pub has_subscribed: bool,
     pub has_verified_email: Option<bool>,
+    #[serde(default)]
     pub is_employee: bool,
+    #[serde(default)]
     pub is_friend: bool,
+    #[serde(default)]
     pub is_mod: bool,
+    #[serde(default, deserialize_with = "nullable")]
     pub link_karma: i32,
+    #[serde(default, deserialize_with = "nullable")]
     pub comment_karma: i32,
+    #[serde(default, deserialize_with = "nullable")]
     pub hide_from_robots: bool,
+    #[serde(default, deserialize_with = "nullable")]
     pub pref_show_snoovatar: bool,
     pub icon_img: Option<String>,
     pub subreddit: Option<SubredditInfo>,
+
+    #[serde(with = "timestamp::option", default)]
+    pub created: Option<DateTime<Utc>>,
+    #[serde(with = "timestamp::option", default)]
+    pub created_utc: Option<DateTime<Utc>>,
+
+    /// Any fields reddit sent that are not part of the model.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
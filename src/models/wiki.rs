@@ -0,0 +1,13 @@
+use crate::models::timestamp;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WikiPageInfo {
+    pub content_md: String,
+    pub content_html: Option<String>,
+    pub may_revise: Option<bool>,
+    #[serde(with = "timestamp::option", default)]
+    pub revision_date: Option<DateTime<Utc>>,
+    pub revision_id: Option<String>,
+}
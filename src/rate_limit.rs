@@ -45,10 +45,7 @@ impl RateLimiter {
     }
 
     pub fn should_update(&self) -> bool {
-        match self {
-            RateLimiter::Off => false,
-            _ => true,
-        }
+        !matches!(self, RateLimiter::Off)
     }
 
     pub fn update(&self, tracker: RateLimiterTracker) {
@@ -66,6 +63,12 @@ pub struct RateLimiterTracker {
     reset_time: Instant,
 }
 
+impl Default for RateLimiterTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl RateLimiterTracker {
     pub fn new() -> Self {
         RateLimiterTracker {
@@ -81,8 +84,8 @@ impl RateLimiterTracker {
 
     pub fn from_values(remaning: i32, used: i32, reset_time: i32) -> Self {
         RateLimiterTracker {
-            remaning: remaning,
-            used: used,
+            remaning,
+            used,
             reset_time: Instant::now() + Duration::from_secs(reset_time as u64),
         }
     }
@@ -97,6 +100,12 @@ impl RateLimiterTracker {
 #[derive(Clone)]
 pub struct RateLimiterBatched(Arc<Mutex<Cell<RateLimiterTracker>>>);
 
+impl Default for RateLimiterBatched {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl RateLimiterBatched {
     pub fn new() -> Self {
         RateLimiterBatched(RateLimiterTracker::new_safe())
@@ -136,6 +145,12 @@ impl RateLimiterBatched {
 #[derive(Clone)]
 pub struct RateLimiterPaced(Arc<Mutex<Cell<RateLimiterTracker>>>);
 
+impl Default for RateLimiterPaced {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl RateLimiterPaced {
     pub fn new() -> Self {
         RateLimiterPaced(RateLimiterTracker::new_safe())
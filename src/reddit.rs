@@ -2,37 +2,90 @@
 use crate::models::{
     PostInfo,
     CommentData,
+    CommentListing,
+    DistinguishHow,
+    Fullname,
+    ThingKind,
     ListingData, 
+    MediaAssetLease,
+    ModerateData,
+    PostEditText,
+    ReportReason,
+    ApplyRemovalReason,
+    RemovalMessageType,
+    SendComment,
+    SendJsonForm,
+    SendRemovalMessage,
+    SendMediaAsset,
+    SendDistinguish,
+    SendRemove,
+    SendReport,
+    SavedCategories,
+    SavedCategory,
+    SendSave,
+    SendThingId,
+    SendVote,
+    VoteDirection,
     RedditResponseGeneric,
-    RedditJsonApiType
+    RedditJsonApiType,
+    ThingListing,
+    ThingsResponse
 };
 
 use crate::items::{
+    media::{MediaFile, UploadedMedia},
+    modmail::Modmail,
     search::{PostSearch, SubredditSearch, UserSearch},
-    submission::Submission,
+    post::Post,
+    submission::{Comment, Submission, SubmissionOptions},
     subreddit::SubredditLink,
+    thing::{InfoResults, Thing},
     user::RedditUserLink,
     AbstractedApi,
 };
 
-use crate::endpoints::{self, Endpoint, EndpointBuilder, SearchSort};
+use crate::endpoints::{self, CommentSort, Endpoint, EndpointBuilder, SearchSort};
+use crate::error::SnooError;
+use crate::link::{self, RedditLink};
 
 use crate::rate_limit::RateLimiter;
 use crate::reddit_api::RedditApi;
 
+use reqwest::multipart::{Form, Part};
+use reqwest::{Method, Url};
 use serde::{de::DeserializeOwned, Serialize};
+use std::collections::HashMap;
 use std::io;
 
+/// Max number of fullnames `api/info` accepts in one request.
+const INFO_BATCH_SIZE: usize = 100;
+
+fn join_names(names: &[Fullname]) -> String {
+    names
+        .iter()
+        .map(Fullname::as_str)
+        .collect::<Vec<&str>>()
+        .join(",")
+}
+
 /// A new reddit client.
-/// ```
+/// ```no_run
+/// # use snoo::Reddit;
+/// # fn example() -> std::io::Result<()> {
 /// // An unauthenicated application:
 /// let r = Reddit::new()?;
+/// # Ok(())
+/// # }
 /// ```
 ///
 ///
-/// ```
+/// ```no_run
+/// # use snoo::Reddit;
+/// # async fn example() -> std::io::Result<()> {
 /// // An authenicated script application
 /// let r = Reddit::new_script("snoo-rs", "password", "id", "secret").await?;
+/// # Ok(())
+/// # }
 /// ```
 ///
 #[derive(Clone)]
@@ -106,6 +159,199 @@ impl Reddit {
         self.api.post_api(target_url.to_url(), &RedditJsonApiType::new(data)).await
     }
 
+    /// Creates a post request with a json body to a reddit api
+    pub async fn post_json<S: Serialize, R: DeserializeOwned>(&self, target_url: Endpoint, data: &S) -> io::Result<R> {
+        self.api.post_json_api(target_url.to_url(), &RedditJsonApiType::new(data)).await
+    }
+
+    /// Requests a lease to upload `file` to reddits media storage.
+    pub async fn media_lease(&self, file: &MediaFile) -> io::Result<MediaAssetLease> {
+        let target_url = self.ep(endpoints::MEDIA_ASSET)?;
+        self.api
+            .post_form_api(target_url.to_url(), &SendMediaAsset {
+                filepath: &file.file_name,
+                mimetype: &file.mime_type,
+            })
+            .await
+    }
+
+    /// Uploads `file` to the storage given by `lease`.
+    pub async fn upload_to_lease(&self, lease: &MediaAssetLease, file: &MediaFile) -> io::Result<UploadedMedia> {
+        let upload_url = lease.upload_url();
+        let target_url = Url::parse(&upload_url).map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("Bad upload url: {}", upload_url))
+        })?;
+
+        let mut form = Form::new();
+        for field in &lease.args.fields {
+            form = form.text(field.name.clone(), field.value.clone());
+        }
+        let part = Part::bytes(file.data.clone())
+            .file_name(file.file_name.clone())
+            .mime_str(&file.mime_type)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "Bad mime type"))?;
+        form = form.part("file", part);
+
+        self.api.upload_multipart(target_url, form).await?;
+
+        let key = lease.field("key").ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "No key in upload lease")
+        })?;
+        Ok(UploadedMedia {
+            asset_id: lease.asset.asset_id.clone(),
+            url: format!("{}/{}", upload_url.trim_end_matches('/'), key),
+        })
+    }
+
+    /// Uploads a file so it can be used in an image, video or gallery post.
+    pub async fn upload_media(&self, file: &MediaFile) -> io::Result<UploadedMedia> {
+        let lease = self.media_lease(file).await?;
+        self.upload_to_lease(&lease, file).await
+    }
+
+    /// Creates a post request to a reddit api that dosent return any data
+    pub async fn post_action<S: Serialize>(&self, target_url: Endpoint, data: &S) -> io::Result<()> {
+        self.api.post_action(target_url.to_url(), &RedditJsonApiType::new(data)).await
+    }
+
+    /// Votes on a post or comment.
+    /// Fails with [SnooError::Archived] if the thing is archived.
+    pub(crate) async fn vote(&self, thing: &ModerateData, direction: VoteDirection) -> io::Result<()> {
+        if thing.archived {
+            Err(SnooError::Archived(thing.name.clone()))?;
+        }
+
+        let target_url = self.ep(endpoints::VOTE)?;
+        self.post_action(target_url, &SendVote {
+            id: &thing.name,
+            dir: direction.dir(),
+        }).await
+    }
+
+    /// Saves a post or comment.
+    /// `category` can only be used by accounts with reddit premium.
+    pub(crate) async fn save(&self, name: &str, category: Option<&str>) -> io::Result<()> {
+        let target_url = self.ep(endpoints::SAVE)?;
+        self.post_action(target_url, &SendSave { id: name, category }).await
+    }
+
+    pub(crate) async fn unsave(&self, name: &str) -> io::Result<()> {
+        let target_url = self.ep(endpoints::UNSAVE)?;
+        self.post_action(target_url, &SendThingId { id: name }).await
+    }
+
+    /// Edits the text of a post or comment and
+    /// returns the updated model from the response.
+    pub(crate) async fn edit_text<T: DeserializeOwned>(&self, name: &str, new_text: &str) -> io::Result<T> {
+        let target_url = self.ep(endpoints::EDIT)?;
+        let mut resp = self.post_data::<_, ThingsResponse<RedditResponseGeneric<T>>>(target_url, &PostEditText {
+            thing_id: name,
+            new_text
+        }).await?;
+
+        if resp.things.is_empty() {
+            Err(io::Error::new(io::ErrorKind::InvalidData, "No things in response."))?;
+        }
+        Ok(resp.things.swap_remove(0).data)
+    }
+
+    /// Sends a moderator action that only takes the things fullname.
+    /// e.g. `api/approve`, `api/lock`
+    pub(crate) async fn thing_action(&self, ep: EndpointBuilder, name: &str) -> io::Result<()> {
+        let target_url = self.ep(ep)?;
+        self.post_action(target_url, &SendThingId { id: name }).await
+    }
+
+    /// Removes a post or comment as a moderator.
+    pub(crate) async fn remove(&self, name: &str, spam: bool) -> io::Result<()> {
+        let target_url = self.ep(endpoints::REMOVE)?;
+        self.post_action(target_url, &SendRemove { id: name, spam }).await
+    }
+
+    pub(crate) async fn distinguish(&self, name: &str, how: DistinguishHow, sticky: Option<bool>) -> io::Result<()> {
+        let target_url = self.ep(endpoints::DISTINGUISH)?;
+        self.post_action(target_url, &SendDistinguish {
+            id: name,
+            how: how.to_str(),
+            sticky,
+        }).await
+    }
+
+    /// Attaches a removal reason and/or a note for other moderators to a removed post or comment.
+    pub(crate) async fn apply_removal_reason(&self, name: &str, reason_id: Option<&str>, mod_note: Option<&str>) -> io::Result<()> {
+        let target_url = self.ep(endpoints::REMOVAL_REASONS)?;
+        let json = serde_json::to_string(&ApplyRemovalReason {
+            item_ids: vec![name],
+            reason_id,
+            mod_note,
+        })?;
+        self.api.form_action(Method::POST, target_url.to_url(), &SendJsonForm { json }).await
+    }
+
+    /// Sends the author of a removed post or comment a removal message.
+    pub(crate) async fn send_removal_message(&self, name: &str, message: &str, title: &str, message_type: RemovalMessageType) -> io::Result<()> {
+        let ep = if name.starts_with("t1_") {
+            endpoints::REMOVAL_COMMENT_MESSAGE
+        } else {
+            endpoints::REMOVAL_LINK_MESSAGE
+        };
+        let json = serde_json::to_string(&SendRemovalMessage {
+            item_id: vec![name],
+            message,
+            title,
+            message_type: message_type.to_str(),
+        })?;
+        self.api.form_action(Method::POST, self.ep(ep)?.to_url(), &SendJsonForm { json }).await
+    }
+
+    /// Replies to a post or comment and returns the new comment.
+    pub(crate) async fn comment(&self, parent: &str, text: &str) -> io::Result<Comment<'_>> {
+        let target_url = self.ep(endpoints::COMMENT)?;
+        let mut resp = self.post_data::<_, ThingsResponse<RedditResponseGeneric<CommentData>>>(target_url, &SendComment {
+            thing_id: parent,
+            text,
+        }).await?;
+
+        if resp.things.is_empty() {
+            Err(io::Error::new(io::ErrorKind::InvalidData, "No things in response."))?;
+        }
+        Ok(self.bind::<Comment>(resp.things.swap_remove(0).data))
+    }
+
+    /// Deletes a post or comment made by the authenticated user.
+    pub(crate) async fn delete(&self, name: &str) -> io::Result<()> {
+        let target_url = self.ep(endpoints::DEL)?;
+        self.post_action(target_url, &SendThingId { id: name }).await
+    }
+
+    /// Reports a post or comment to the subreddits moderators.
+    pub(crate) async fn report(&self, name: &str, reason: ReportReason<'_>) -> io::Result<()> {
+        reason.validate()?;
+        let target_url = self.ep(endpoints::REPORT)?;
+        self.post_action(target_url, &SendReport::new(name, reason)).await
+    }
+
+    /// Hides posts from the users listings.
+    /// All the posts are hidden with a single request.
+    pub async fn hide(&self, names: &[Fullname]) -> io::Result<()> {
+        let target_url = self.ep(endpoints::HIDE)?;
+        self.post_action(target_url, &SendThingId { id: &join_names(names) }).await
+    }
+
+    /// Unhides posts that were hidden with [Reddit::hide].
+    pub async fn unhide(&self, names: &[Fullname]) -> io::Result<()> {
+        let target_url = self.ep(endpoints::UNHIDE)?;
+        self.post_action(target_url, &SendThingId { id: &join_names(names) }).await
+    }
+
+    /// The categories the authenticated user has saved things into.
+    /// Only available with reddit premium.
+    pub async fn saved_categories(&self) -> io::Result<Vec<SavedCategory>> {
+        let target_url = self.ep(endpoints::SAVED_CATEGORIES)?;
+        let resp = self.api.get_api::<SavedCategories>(target_url.to_url()).await?;
+        Ok(resp.categories)
+    }
+
     /// Sets the state of a thing
     pub async fn set_state<T: Serialize>(&self, target_url: Endpoint, id:&str, state: T) -> io::Result<()>{
         self.api.set_state(target_url.to_url(), id, state).await
@@ -126,15 +372,20 @@ impl Reddit {
     }
 
     // Get a user by name
-    pub fn user(&self, username: &str) -> RedditUserLink {
+    pub fn user(&self, username: &str) -> RedditUserLink<'_> {
         RedditUserLink::new(self, username)
     }
 
     //get a subreddit by name
-    pub fn subreddit(&self, name: &str) -> SubredditLink {
+    pub fn subreddit(&self, name: &str) -> SubredditLink<'_> {
         SubredditLink::new(self, name)
     }
 
+    /// Modmail of every subreddit the authenticated user moderates.
+    pub fn modmail(&self) -> Modmail<'_> {
+        Modmail::new(self)
+    }
+
     /// Search over all of reddit
     pub async fn search<'r, 's>(
         &'r self,
@@ -165,15 +416,219 @@ impl Reddit {
         UserSearch::new_search(self, search_ep, query, sort).await
     }
 
-    /// Get [Submission] from a post url
-    /// TODO: FIX THIS
-    pub async fn submission_from_link(&self, url: &'_ str) -> io::Result<Submission<'_>> {
-        let page_link = self.ep_str(url)?;
+    /// Fetches posts, comments and subreddits by their fullname.
+    ///
+    /// The names are requested in batches of 100 which are sent
+    /// concurrently through the rate limiter.
+    pub async fn info(&self, names: &[Fullname]) -> io::Result<InfoResults<'_>> {
+        let ep = self.ep(endpoints::INFO)?;
+        self.info_from(ep, names).await
+    }
+
+    /// [Reddit::info] with `info_ep` as the `api/info` endpoint.
+    async fn info_from(&self, info_ep: Endpoint, names: &[Fullname]) -> io::Result<InfoResults<'_>> {
+        let mut requests = Vec::new();
+        for batch in names.chunks(INFO_BATCH_SIZE) {
+            let ep = info_ep.clone().add_query_pairs(&[("id", &join_names(batch))]);
 
+            let api = self.api.clone();
+            requests.push(tokio::spawn(async move {
+                api.get_api::<RedditResponseGeneric<ThingListing>>(ep.to_url())
+                    .await
+            }));
+        }
+
+        let mut things = HashMap::new();
+        for request in requests {
+            let listing = request
+                .await
+                .map_err(|e| {
+                    io::Error::new(
+                        io::ErrorKind::Interrupted,
+                        format!("Info request did not complete. {}", e),
+                    )
+                })??
+                .data;
+
+            for thing in listing.children {
+                things.insert(thing.name().to_owned(), thing);
+            }
+        }
+
+        let mut results = InfoResults {
+            found: Vec::new(),
+            missing: Vec::new(),
+            deleted: Vec::new(),
+        };
+
+        for name in names {
+            match things.get(name.as_str()) {
+                Some(thing) => {
+                    if thing.is_deleted() {
+                        results.deleted.push(name.clone());
+                    }
+                    results.found.push(self.bind::<Thing>(thing.clone()));
+                }
+                None => results.missing.push(name.clone()),
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Resolves any reddit link into a [RedditLink].
+    /// Share links are followed to find where they point.
+    pub async fn resolve_link(&self, link: &str) -> io::Result<RedditLink> {
+        match link::share_link_url(link)? {
+            Some(share_url) => {
+                let target = self.api.resolve_redirect(share_url).await?;
+                RedditLink::parse(target.as_str())
+            }
+            None => RedditLink::parse(link),
+        }
+    }
+
+    async fn load_submission(&self, ep: Endpoint, sort: Option<CommentSort>) -> io::Result<Submission<'_>> {
         let (post, comment) = self.api
-            .get_api::<(RedditResponseGeneric<ListingData<PostInfo>>, RedditResponseGeneric<ListingData<CommentData>>)>(page_link.to_url())
+            .get_api::<(RedditResponseGeneric<ListingData<PostInfo>>, RedditResponseGeneric<CommentListing>)>(ep.to_url())
             .await?;
 
-        Ok(Submission::from_resp(self, post.data, comment.data))
+        Ok(Submission::from_resp(self, post.data, comment.data, sort))
+    }
+
+    /// Get a [Post] by its fullname or id. e.g. `t3_gkxqzq` or `gkxqzq`
+    pub async fn post(&self, name: &str) -> io::Result<Post<'_>> {
+        let name = Fullname::new(ThingKind::Link, name.trim_start_matches("t3_"));
+        let ep = self.ep(endpoints::INFO)?.add_query_pairs(&[("id", name.as_str())]);
+
+        let info = self.get_list::<PostInfo>(ep).await?.into_iter().next().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, format!("No post found for {}", name))
+        })?;
+        Ok(self.bind::<Post>(info))
+    }
+
+    /// Get a [Submission] by its id. e.g. `gkxqzq`
+    pub async fn submission(&self, id: &str) -> io::Result<Submission<'_>> {
+        let ep = self.ep(endpoints::SUBMISSION.id(id))?;
+        self.load_submission(ep, None).await
+    }
+
+    /// Get a [Submission] with the comments loaded using `options`.
+    pub async fn submission_with(&self, id: &str, options: &SubmissionOptions) -> io::Result<Submission<'_>> {
+        let ep = options.apply(self.ep(endpoints::SUBMISSION.id(id))?);
+        self.load_submission(ep, options.comment_sort()).await
+    }
+
+    /// Get a [Submission] focused on a single comment,
+    /// with `context` parent comments loaded above it.
+    pub async fn submission_comment(
+        &self,
+        id: &str,
+        comment_id: &str,
+        context: Option<u32>,
+    ) -> io::Result<Submission<'_>> {
+        let mut options = SubmissionOptions::new().comment(comment_id);
+        if let Some(context) = context {
+            options = options.context(context);
+        }
+        self.submission_with(id, &options).await
+    }
+
+    /// Get [Submission] from any link to a post or comment.
+    /// See [Reddit::resolve_link] for the supported links.
+    pub async fn submission_from_link(&self, url: &'_ str) -> io::Result<Submission<'_>> {
+        match self.resolve_link(url).await? {
+            RedditLink::Submission { id } => self.submission(&id).await,
+            RedditLink::Comment {
+                submission_id,
+                comment_id,
+                context,
+            } => {
+                self.submission_comment(&submission_id, &comment_id, context)
+                    .await
+            }
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Not a link to a submission: {}", url),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures::comment_json;
+    use crate::test_server::serve;
+    use serde_json::json;
+
+    /// Answers `api/info` with the requested comments in reverse order,
+    /// leaving out `t1_c5` and with `t1_c7` deleted.
+    fn info_response(request: &str) -> (&'static str, String) {
+        let path = request.split(' ').nth(1).unwrap();
+        let url = Url::parse(&format!("http://localhost{}", path)).unwrap();
+        let ids = url
+            .query_pairs()
+            .find(|(k, _)| k == "id")
+            .map(|(_, v)| v.into_owned())
+            .unwrap();
+
+        let children: Vec<_> = ids
+            .split(',')
+            .rev()
+            .filter(|name| *name != "t1_c5")
+            .map(|name| {
+                let id = name.trim_start_matches("t1_");
+                let author = if name == "t1_c7" {
+                    "[deleted]"
+                } else {
+                    "someone"
+                };
+                comment_json(id, "t3_link", "t3_link", json!({ "author": author }))
+            })
+            .collect();
+        let listing = json!({"kind": "Listing", "data": {
+            "modhash": null, "dist": children.len(), "children": children,
+            "after": null, "before": null
+        }});
+        ("200 OK", listing.to_string())
+    }
+
+    #[tokio::test]
+    async fn info_batches_and_keeps_order() {
+        let (base, requests) = serve(info_response);
+        let reddit = Reddit::new().unwrap();
+        let ep = Endpoint::from_url(Url::parse(&format!("{}/api/info.json", base)).unwrap());
+
+        let names: Vec<Fullname> = (0..250)
+            .map(|i| Fullname::new(ThingKind::Comment, &format!("c{}", i)))
+            .collect();
+        let results = reddit.info_from(ep, &names).await.unwrap();
+
+        let mut batch_sizes: Vec<usize> = requests
+            .iter()
+            .take(3)
+            .map(|r| r.lines().next().unwrap().matches("t1_c").count())
+            .collect();
+        batch_sizes.sort();
+        assert_eq!(batch_sizes, [50, 100, 100]);
+
+        let found: Vec<&str> = results.found.iter().map(Thing::name).collect();
+        let expected: Vec<&str> = names
+            .iter()
+            .map(Fullname::as_str)
+            .filter(|n| *n != "t1_c5")
+            .collect();
+        assert_eq!(found, expected);
+        assert_eq!(results.missing, [Fullname::parse("t1_c5").unwrap()]);
+        assert_eq!(results.deleted, [Fullname::parse("t1_c7").unwrap()]);
+    }
+
+    #[tokio::test]
+    async fn info_without_names() {
+        let reddit = Reddit::new().unwrap();
+        let ep = Endpoint::from_url(Url::parse("http://127.0.0.1:9/api/info.json").unwrap());
+        let results = reddit.info_from(ep, &[]).await.unwrap();
+        assert!(results.found.is_empty() && results.missing.is_empty());
     }
 }
@@ -2,7 +2,7 @@
 //! rate limiting and authentication
 use crate::rate_limit::{RateLimiter, RateLimiterTracker};
 
-use reqwest::{Client, Response, Url};
+use reqwest::{multipart::Form, Client, Method, Response, Url};
 use serde::de::DeserializeOwned;
 use serde::ser::Serialize;
 use std::io;
@@ -10,7 +10,7 @@ use serde_urlencoded;
 
 use crate::endpoints::{self, Endpoint, EndpointBase, EndpointBuilder};
 
-use crate::models::{RedditSetState, RedditPostResponse};
+use crate::models::{RedditActionResponse, RedditPostResponse, RedditPostResponseJson, RedditSetState};
 use crate::models::auth::{AuthResponse, OAuthMeResponse};
 
 #[derive(Clone)]
@@ -30,7 +30,7 @@ pub struct RedditApi {
 static USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),);
 
 fn reqwest_to_io_err(error: reqwest::Error) -> io::Error {
-    io::Error::new(io::ErrorKind::Other, format!("{}", error))
+    io::Error::other(format!("{}", error))
 }
 
 impl RedditApi {
@@ -47,7 +47,7 @@ impl RedditApi {
                 )
             })?;
         Ok(Self {
-            client: client,
+            client,
             rate_limiter: RateLimiter::Off,
             auth: AuthType::None,
         })
@@ -272,16 +272,44 @@ impl RedditApi {
         Ok(data)
     }
 
+    /// GET request to an api that wraps its response
+    /// in `{"json": {"errors": [], "data": ...}}`
+    pub async fn get_json_api<R: DeserializeOwned>(&self, target_url: Url) -> io::Result<R> {
+        let api_resp = self.get_api::<RedditPostResponse<R>>(target_url).await?.json;
+
+        check_api_errors(&api_resp)?;
+        api_resp.data.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "No data in response."))
+    }
+
+    /// Follows any redirects from `target_url` and
+    /// returns the url that was finally reached.
+    pub async fn resolve_redirect(&self, target_url: Url) -> io::Result<Url> {
+        let req = self.client.get(target_url);
+        let resp = self.send_request(req).await?;
+        Ok(resp.url().clone())
+    }
+
      /// post request to reddit api with json response
-    pub async fn post_api<R: DeserializeOwned, D: Serialize>(&self, mut target_url: Url, data: &D) -> io::Result<R> {
+    pub async fn post_api<R: DeserializeOwned, D: Serialize>(&self, target_url: Url, data: &D) -> io::Result<R> {
+        let resp = self.send_post(target_url, data).await?;
 
-        let url_str= serde_urlencoded::to_string(data).map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "bad url query"))?;
-        target_url.set_query(Some(&url_str));
+        let api_resp = resp.json::<RedditPostResponse<R>>().await.map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::ConnectionAborted,
+                format!("Failed to deseralize response. {}", e),
+            )
+        })?.json;
 
-        let req = self.client.post(target_url);
+        check_api_errors(&api_resp)?;
+        api_resp.data.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "No data in response."))
+    }
+
+    /// post request with a json body to a reddit api with json response.
+    /// e.g. `api/submit_poll_post`
+    pub async fn post_json_api<R: DeserializeOwned, D: Serialize>(&self, target_url: Url, data: &D) -> io::Result<R> {
+        let req = self.client.post(target_url).json(data);
         let resp = self.send_request(req).await?;
 
-       // return  Err(io::Error::new(io::ErrorKind::Other, "Early exit"));
         let api_resp = resp.json::<RedditPostResponse<R>>().await.map_err(|e| {
             io::Error::new(
                 io::ErrorKind::ConnectionAborted,
@@ -289,27 +317,109 @@ impl RedditApi {
             )
         })?.json;
 
-        if api_resp.errors.len() > 0 {
-            let errs =  api_resp.errors.iter().flatten().map(|e: &String| e.as_ref()).collect::<Vec<&str>>();
-            Err(
-                io::Error::new(io::ErrorKind::Other, format!("Api errors: [{}]", errs.join(", ")))
-            )?;
+        check_api_errors(&api_resp)?;
+        api_resp.data.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "No data in response."))
+    }
+
+    /// post request with a form body to an api that does not wrap
+    /// its response in `{"json": ...}`
+    /// e.g. `api/media/asset`
+    pub async fn post_form_api<R: DeserializeOwned, D: Serialize>(&self, target_url: Url, data: &D) -> io::Result<R> {
+        let req = self.client.post(target_url).form(data);
+        let resp = self.send_request(req).await?;
+
+        resp.json::<R>().await.map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::ConnectionAborted,
+                format!("Failed to deseralize response. {}", e),
+            )
+        })
+    }
+
+    /// Sends `data` as a form body with any http method and ignores the response body.
+    /// e.g. `PUT api/v1/<subreddit>/removal_reasons/<id>`
+    pub async fn form_action<D: Serialize>(&self, method: Method, target_url: Url, data: &D) -> io::Result<()> {
+        let req = self.client.request(method, target_url).form(data);
+        self.send_request(req).await?;
+        Ok(())
+    }
+
+    /// Request without a body to a reddit api, the response body is ignored.
+    /// e.g. `POST api/mod/conversations/<id>/archive`
+    pub async fn empty_action(&self, method: Method, target_url: Url) -> io::Result<()> {
+        let req = self.client.request(method, target_url);
+        self.send_request(req).await?;
+        Ok(())
+    }
+
+    /// DELETE request to a reddit api, the response body is ignored.
+    pub async fn delete_api(&self, target_url: Url) -> io::Result<()> {
+        let req = self.client.delete(target_url);
+        self.send_request(req).await?;
+        Ok(())
+    }
+
+    /// Uploads a multipart form to a url outside of reddit,
+    /// so no auth or rate limiting is applied.
+    pub async fn upload_multipart(&self, target_url: Url, form: Form) -> io::Result<()> {
+        let resp = self.client.post(target_url).multipart(form).send().await.map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::ConnectionAborted,
+                format!("Failed to upload file. {}", e),
+            )
+        })?;
+
+        if !resp.status().is_success() {
+            Err(io::Error::other(format!("Upload failed: {}", resp.status())))?;
         }
+        Ok(())
+    }
 
-        api_resp.data.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "No data in response."))
-        
+    /// post request to a reddit api that dosent return any data.
+    /// e.g. `api/vote` which responds with `{}`
+    pub async fn post_action<D: Serialize>(&self, target_url: Url, data: &D) -> io::Result<()> {
+        let resp = self.send_post(target_url, data).await?;
+
+        let api_resp = resp.json::<RedditActionResponse>().await.map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::ConnectionAborted,
+                format!("Failed to deseralize response. {}", e),
+            )
+        })?;
+
+        if let Some(json) = &api_resp.json {
+            check_api_errors(json)?;
+        }
+        Ok(())
+    }
+
+    async fn send_post<D: Serialize>(&self, mut target_url: Url, data: &D) -> io::Result<Response> {
+        let url_str= serde_urlencoded::to_string(data).map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "bad url query"))?;
+        target_url.set_query(Some(&url_str));
+
+        let req = self.client.post(target_url);
+        self.send_request(req).await
     }
 
     /// Setst the state of a thing
     pub async fn set_state<T: Serialize>(&self, target_url: Url, id:&str, state: T) -> io::Result<()>{
-        self.post_api(target_url, &RedditSetState{
+        self.post_action(target_url, &RedditSetState{
             id,
             state
-        }).await?;
-        Ok(())
+        }).await
     }
 }
 
+fn check_api_errors<T>(resp: &RedditPostResponseJson<T>) -> io::Result<()> {
+    if !resp.errors.is_empty() {
+        let errs =  resp.errors.iter().flatten().map(|e: &String| e.as_ref()).collect::<Vec<&str>>();
+        Err(
+            io::Error::other(format!("Api errors: [{}]", errs.join(", ")))
+        )?;
+    }
+    Ok(())
+}
+
 /// Listens on authenticationc callback url for a
 /// autentication code
 pub struct RedditApiAuthenticationUrl {
@@ -442,3 +552,32 @@ impl RedditApiScope {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::serve_static;
+    use serde_json::{json, Value};
+
+    #[tokio::test]
+    async fn post_form_api_sends_form_body() {
+        let (base, request) = serve_static("200 OK", r#"{"ok": true}"#);
+        let url = Url::parse(&format!("{}/api/mod/conversations?raw_json=1", base)).unwrap();
+        let api = RedditApi::new().unwrap();
+
+        let resp: Value = api
+            .post_form_api(url, &json!({"subject": "Hi there", "to": "a_user"}))
+            .await
+            .unwrap();
+        assert_eq!(resp, json!({"ok": true}));
+
+        let request = request.recv().unwrap();
+        let (head, body) = request.split_at(request.find("\r\n\r\n").unwrap());
+        // The query of the url is kept and the data is not added to it.
+        assert!(head.starts_with("POST /api/mod/conversations?raw_json=1 HTTP/1.1"));
+        assert!(head
+            .to_ascii_lowercase()
+            .contains("content-type: application/x-www-form-urlencoded"));
+        assert_eq!(body.trim(), "subject=Hi+there&to=a_user");
+    }
+}
@@ -0,0 +1,76 @@
+//! A local http server for tests that check the requests sent to it.
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc;
+use std::thread;
+
+/// Answers every request with the status and body returned by `respond`
+/// and sends the raw requests back.
+/// Returns the base url of the server. e.g. `http://127.0.0.1:1234`
+pub(crate) fn serve<F>(respond: F) -> (String, mpsc::Receiver<String>)
+where
+    F: Fn(&str) -> (&'static str, String) + Send + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let request = read_request(&mut stream);
+            let (status, body) = respond(&request);
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\n\
+                 Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+            if tx.send(request).is_err() {
+                break;
+            }
+        }
+    });
+
+    (format!("http://{}", addr), rx)
+}
+
+/// Answers every request with `status` and `body`.
+pub(crate) fn serve_static(
+    status: &'static str,
+    body: &'static str,
+) -> (String, mpsc::Receiver<String>) {
+    serve(move |_| (status, body.to_owned()))
+}
+
+fn read_request(stream: &mut TcpStream) -> String {
+    let mut request = Vec::new();
+    let mut buf = [0; 4096];
+    loop {
+        let n = stream.read(&mut buf).unwrap();
+        request.extend_from_slice(&buf[..n]);
+        if n == 0 || is_complete(&request) {
+            break;
+        }
+    }
+    String::from_utf8_lossy(&request).into_owned()
+}
+
+fn is_complete(request: &[u8]) -> bool {
+    let request = String::from_utf8_lossy(request);
+    let (head, body) = match request.find("\r\n\r\n") {
+        Some(i) => (&request[..i], &request[i + 4..]),
+        None => return false,
+    };
+    let content_length = head
+        .lines()
+        .filter_map(|l| l.split_once(':'))
+        .find(|(k, _)| k.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, v)| v.trim().parse::<usize>().ok());
+    match content_length {
+        Some(len) => body.len() >= len,
+        None => body.ends_with("0\r\n\r\n") || !head.to_ascii_lowercase().contains("chunked"),
+    }
+}